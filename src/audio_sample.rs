@@ -0,0 +1,37 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+impl<'a> ::AudioSample<'a> {
+    /// Returns the path of the file on the upload server of Wikimedia Commons, such as `7/7e/De-Haus.ogg` for `De-Haus.ogg`.
+    ///
+    /// The path is relative to `https://upload.wikimedia.org/wikipedia/commons/`. It's computed without any network access the same way as MediaWiki does: the file name is normalized by replacing spaces with underscores and capitalizing the first letter, and the file is put in the directories named after the first one and the first two hexadecimal digits of the MD5 hash of the normalized file name.
+    #[must_use]
+    pub fn commons_path(&self) -> String {
+        let mut characters = self.file_name.trim().chars();
+        let file_name: String = characters
+            .next()
+            .into_iter()
+            .flat_map(char::to_uppercase)
+            .chain(characters)
+            .map(|character| if character == ' ' { '_' } else { character })
+            .collect();
+        let hash = format!("{:x}", ::md5::compute(&file_name));
+        format!("{}/{}/{}", &hash[..1], &hash[..2], file_name)
+    }
+}
+
+pub fn parse_region(file_name: &str) -> Option<::Label> {
+    let mut parts = file_name.splitn(3, '-');
+    parts.next()?;
+    let region = parts.next()?;
+    parts.next()?;
+    Some(match &region.to_lowercase() as _ {
+        "at" => ::Label::Austrian,
+        "ch" => ::Label::Swiss,
+        "de" => ::Label::GermanyGerman,
+        "gb" | "uk" => ::Label::British,
+        "us" => ::Label::American,
+        _ => return None,
+    })
+}
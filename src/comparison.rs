@@ -0,0 +1,54 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_comparison<'a>(
+    context: &mut ::Context<'a>,
+    parameters: &[::Parameter<'a>],
+) -> ::Comparison<'a> {
+    let mut comparison = ::Comparison::default();
+    for parameter in parameters {
+        let (name, variant, alternative) = match ::overview::parse_parameter(context, parameter) {
+            None => continue,
+            Some(parsed) => parsed,
+        };
+        let forms = match name {
+            "Bild" => continue,
+            "Komparativ" => &mut comparison.comparative,
+            "Positiv" => &mut comparison.positive,
+            "Superlativ" => {
+                if let Some(mut form) = ::overview::parse_form(parameter) {
+                    // The German template adds the word “am” in front of the superlative, but some entries include it in the parameter anyway.
+                    if context.language == Some(::Language::De) {
+                        if form.starts_with("am ") {
+                            form = ::slice_cow(&form, 3, form.len());
+                        }
+                        comparison.predicative_superlative.push(::OverviewForm {
+                            alternative,
+                            form: ::Cow::Owned(format!("am {}", form)),
+                            variant,
+                        });
+                    }
+                    comparison.superlative.push(::OverviewForm {
+                        alternative,
+                        form,
+                        variant,
+                    });
+                }
+                continue;
+            }
+            _ => {
+                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                continue;
+            }
+        };
+        if let Some(form) = ::overview::parse_form(parameter) {
+            forms.push(::OverviewForm {
+                alternative,
+                form,
+                variant,
+            });
+        }
+    }
+    comparison
+}
@@ -0,0 +1,118 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+impl<'a> ::Etymology<'a> {
+    /// Recognizes the word formation of a German word from its etymology.
+    ///
+    /// The etymology is expected to start with the kind of word formation, optionally as a link, followed by the constituents as links or italic text, each preceded by its part of speech if given as text or a link, as in `Determinativkompositum aus den Substantiven [[Ausstellung]] und [[wohnen]] sowie dem Fugenelement [[-s]]`. A part of speech or linking element in the singular applies to the constituent following it, and one in the plural to all the constituents following it until another one is given. Text in parentheses, such as a grammatical term explaining the one before it as in `mit dem Derivatem ([[Ableitungsmorphem]]) ''[[-ung]]''`, is not taken to be a constituent. Parsing ends at the end of the first sentence.
+    ///
+    /// Returns `None` if the etymology doesn't match this pattern, if a compound has fewer than two constituents or if more than one linking element is given.
+    #[must_use]
+    pub fn compound(&self) -> Option<::Compound<'a>> {
+        let mut constituents = vec![];
+        let mut italic = false;
+        let mut kind = None;
+        let mut linking_element = None;
+        let mut marker = None;
+        let mut parenthesized = false;
+        for item in &self.content {
+            let lemma = match item {
+                ::Flowing::Bold | ::Flowing::Reference => continue,
+                ::Flowing::Italic => {
+                    italic = !italic;
+                    continue;
+                }
+                ::Flowing::Link { target, text } => {
+                    if kind.is_none() {
+                        kind = Some(parse_kind(text)?);
+                        continue;
+                    }
+                    if let Some(new_marker) = ::etymology::parse_markers(text) {
+                        marker = Some(new_marker);
+                        continue;
+                    }
+                    if parenthesized {
+                        continue;
+                    }
+                    target.clone()
+                }
+                ::Flowing::Text { value } if italic && kind.is_some() => {
+                    if parenthesized {
+                        continue;
+                    }
+                    ::trim_cow(value)
+                }
+                ::Flowing::Text { value } => {
+                    let mut text = value as &str;
+                    if kind.is_none() {
+                        text = text.trim_start();
+                        if text.is_empty() {
+                            continue;
+                        }
+                        let end = text
+                            .find(|character: char| !character.is_alphabetic())
+                            .unwrap_or(text.len());
+                        kind = Some(parse_kind(&text[..end])?);
+                        text = &text[end..];
+                    }
+                    if text.contains(['.', ';']) {
+                        break;
+                    }
+                    if let Some(new_marker) = ::etymology::parse_markers(text) {
+                        marker = Some(new_marker);
+                    }
+                    if let Some(index) = text.rfind(['(', ')']) {
+                        parenthesized = text[index..].starts_with('(');
+                    }
+                    continue;
+                }
+                _ => return None,
+            };
+            if lemma.is_empty() {
+                continue;
+            }
+            let current = marker;
+            if let Some((_, false)) = marker {
+                marker = None;
+            }
+            match current.map(|(marker, _)| marker) {
+                Some(::etymology::Marker::LinkingElement) => {
+                    if linking_element.is_some() {
+                        return None;
+                    }
+                    linking_element = Some(lemma);
+                }
+                Some(::etymology::Marker::Pos(pos)) => constituents.push(::Constituent {
+                    lemma,
+                    pos: Some(pos),
+                }),
+                None => constituents.push(::Constituent { lemma, pos: None }),
+            }
+        }
+        let kind = kind?;
+        let minimum = match kind {
+            ::CompoundKind::Copulative | ::CompoundKind::Determinative => 2,
+            _ => 1,
+        };
+        if constituents.len() < minimum {
+            return None;
+        }
+        Some(::Compound {
+            constituents,
+            kind,
+            linking_element,
+        })
+    }
+}
+
+fn parse_kind(text: &str) -> Option<::CompoundKind> {
+    Some(match &text.trim().to_lowercase() as _ {
+        "ableitung" => ::CompoundKind::Derivation,
+        "determinativkompositum" => ::CompoundKind::Determinative,
+        "konversion" => ::CompoundKind::Conversion,
+        "kopulativkompositum" => ::CompoundKind::Copulative,
+        "zusammenrückung" => ::CompoundKind::Juxtaposition,
+        _ => return None,
+    })
+}
@@ -0,0 +1,562 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// The forms of the auxiliary verbs in the order present, preterite, subjunctive I and subjunctive II, each with the persons in the order of `PERSONS`.
+const HABEN: [[&str; 6]; 4] = [
+    ["habe", "hast", "hat", "haben", "habt", "haben"],
+    ["hatte", "hattest", "hatte", "hatten", "hattet", "hatten"],
+    ["habe", "habest", "habe", "haben", "habet", "haben"],
+    ["hätte", "hättest", "hätte", "hätten", "hättet", "hätten"],
+];
+
+const SEIN: [[&str; 6]; 4] = [
+    ["bin", "bist", "ist", "sind", "seid", "sind"],
+    ["war", "warst", "war", "waren", "wart", "waren"],
+    ["sei", "seiest", "sei", "seien", "seiet", "seien"],
+    ["wäre", "wärest", "wäre", "wären", "wäret", "wären"],
+];
+
+const WERDEN: [[&str; 6]; 4] = [
+    ["werde", "wirst", "wird", "werden", "werdet", "werden"],
+    ["wurde", "wurdest", "wurde", "wurden", "wurdet", "wurden"],
+    ["werde", "werdest", "werde", "werden", "werdet", "werden"],
+    ["würde", "würdest", "würde", "würden", "würdet", "würden"],
+];
+
+const DIPHTHONGS: [&str; 5] = ["ai", "au", "äu", "ei", "eu"];
+
+const INSEPARABLE_PREFIXES: [&str; 8] = ["be", "emp", "ent", "er", "ge", "miss", "ver", "zer"];
+
+const PERSONS: [(::Person, ::Number); 6] = [
+    (::Person::First, ::Number::Singular),
+    (::Person::Second, ::Number::Singular),
+    (::Person::Third, ::Number::Singular),
+    (::Person::First, ::Number::Plural),
+    (::Person::Second, ::Number::Plural),
+    (::Person::Third, ::Number::Plural),
+];
+
+struct Verb<'a> {
+    changed_stem: &'a str,
+    past_participle: Option<String>,
+    prefix: &'a str,
+    stem: &'a str,
+}
+
+// The parameters are `1` for the separable prefix, `2` for the present stem, `3` for the preterite stem, `4` for the subjunctive II stem, `5` for the past participle without the separable prefix and `6` for the changed stem of the second and third person singular present. Parameters `3`, `4` and `5` are required for irregular verbs. For regular verbs the forms are derived from the present stem, and parameter `5` may be given where the past participle isn't regular.
+pub fn parse_conjugation<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    irregular: bool,
+) -> Option<Vec<::InflectedForm>> {
+    let parameters = ::flexion::parse_parameters(
+        context,
+        parameters,
+        &["1", "2", "3", "4", "5", "6", "Hilfsverb", "vp", "zp"],
+    );
+    let stem = parameters.get("2");
+    let preterite_stem = parameters.get("3");
+    let subjunctive_ii_stem = parameters.get("4");
+    if stem.is_none()
+        || irregular
+            && (preterite_stem.is_none()
+                || subjunctive_ii_stem.is_none()
+                || !parameters.contains_key("5"))
+    {
+        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+        return None;
+    }
+    let stem = stem.unwrap();
+    let prefix = parameters
+        .get("1")
+        .map(|prefix| prefix as &str)
+        .unwrap_or("");
+    let mut auxiliaries = vec![];
+    match parameters.get("Hilfsverb") {
+        None => auxiliaries.push(::Auxiliary::Have),
+        Some(value) => {
+            for auxiliary in value.split(',') {
+                match auxiliary.trim() {
+                    "haben" => auxiliaries.push(::Auxiliary::Have),
+                    "sein" => auxiliaries.push(::Auxiliary::Be),
+                    _ => {
+                        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+    let mut passive = |name| match parameters.get(name).map(|value| value as &str) {
+        None | Some("nein") => false,
+        Some("ja") => true,
+        Some(_) => {
+            ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+            false
+        }
+    };
+    let process_passive = passive("vp");
+    let state_passive = passive("zp");
+    let verb = Verb {
+        changed_stem: parameters.get("6").unwrap_or(stem),
+        past_participle: match parameters.get("5") {
+            None => {
+                let past_participle = regular_past_participle(stem);
+                if past_participle.is_none() {
+                    ::add_warning(context, template_node, ::WarningMessage::FormAmbiguous);
+                }
+                past_participle
+            }
+            Some(past_participle) => Some(past_participle.to_string()),
+        }
+        .map(|past_participle| format!("{}{}", prefix, past_participle)),
+        prefix,
+        stem,
+    };
+    let infinitive = format!("{}{}", prefix, infinitive(stem));
+    let mut forms = vec![];
+    push_non_finite(&mut forms, ::NonFinite::Infinitive, infinitive.clone());
+    push_non_finite(
+        &mut forms,
+        ::NonFinite::PresentParticiple,
+        format!("{}d", infinitive),
+    );
+    if let Some(past_participle) = &verb.past_participle {
+        push_non_finite(
+            &mut forms,
+            ::NonFinite::PastParticiple,
+            past_participle.clone(),
+        );
+    }
+    push_non_finite(
+        &mut forms,
+        ::NonFinite::ZuInfinitive,
+        if prefix.is_empty() {
+            format!("zu {}", infinitive)
+        } else {
+            format!("{}zu{}", prefix, self::infinitive(stem))
+        },
+    );
+    for (mood, tense, forms_of_tense) in [
+        (::Mood::Indicative, ::Tense::Present, present(&verb)),
+        (
+            ::Mood::Indicative,
+            ::Tense::Preterite,
+            match preterite_stem {
+                None => weak_preterite(stem),
+                Some(preterite_stem) => strong_preterite(preterite_stem),
+            },
+        ),
+        (::Mood::SubjunctiveI, ::Tense::Present, subjunctive_i(stem)),
+        (
+            ::Mood::SubjunctiveIi,
+            ::Tense::Preterite,
+            match subjunctive_ii_stem {
+                None => weak_preterite(stem),
+                Some(subjunctive_ii_stem) => subjunctive_ii(subjunctive_ii_stem),
+            },
+        ),
+    ] {
+        push_finite(
+            &mut forms,
+            mood,
+            tense,
+            ::Voice::Active,
+            forms_of_tense
+                .iter()
+                .map(|form| with_prefix(form, prefix))
+                .collect(),
+        );
+    }
+    push_imperative(&mut forms, &verb);
+    push_compound(&mut forms, ::Voice::Active, &WERDEN, &infinitive, FUTURE);
+    // The perfect tenses and the passive voices are formed with the past participle, and are left out with it.
+    if let Some(past_participle) = &verb.past_participle {
+        for auxiliary in auxiliaries {
+            let (auxiliary, auxiliary_infinitive) = match auxiliary {
+                ::Auxiliary::Be => (&SEIN, "sein"),
+                ::Auxiliary::Have => (&HABEN, "haben"),
+            };
+            push_compound(&mut forms, ::Voice::Active, auxiliary, past_participle, PERFECT);
+            push_compound(
+                &mut forms,
+                ::Voice::Active,
+                &WERDEN,
+                &format!("{} {}", past_participle, auxiliary_infinitive),
+                FUTURE_PERFECT,
+            );
+        }
+        if process_passive {
+            push_passive(
+                &mut forms,
+                ::Voice::ProcessPassive,
+                &WERDEN,
+                "werden",
+                "worden",
+                past_participle,
+            );
+        }
+        if state_passive {
+            push_passive(
+                &mut forms,
+                ::Voice::StatePassive,
+                &SEIN,
+                "sein",
+                "gewesen",
+                past_participle,
+            );
+        }
+    }
+    Some(forms)
+}
+
+fn infinitive(stem: &str) -> String {
+    if ends_with_schwa_syllable(stem) {
+        format!("{}n", stem)
+    } else {
+        format!("{}en", stem)
+    }
+}
+
+// Stems ending in `d` or `t`, or in `m` or `n` following another consonant than `l`, `r`, `m` or `n`, take an `e` before endings starting with `s` or `t`, as in `arbeitest`, `atmet` and `rechnet`. A `h` following a vowel only lengthens the vowel, as in `gähnt` and `wohnt`.
+fn needs_e(stem: &str) -> bool {
+    let mut characters = stem.chars().rev();
+    match (characters.next(), characters.next()) {
+        (Some('d'), _) | (Some('t'), _) => true,
+        (Some('m'), Some('h')) | (Some('n'), Some('h')) => match characters.next() {
+            None => false,
+            Some(previous) => !"aeiouyäöü".contains(previous),
+        },
+        (Some('m'), Some(previous)) | (Some('n'), Some(previous)) => {
+            !"aeiouyäöülrmn".contains(previous)
+        }
+        _ => false,
+    }
+}
+
+fn ends_with_sibilant(stem: &str) -> bool {
+    stem.ends_with(&['s', 'ß', 'x', 'z'] as &[_])
+}
+
+// Stems such as `wander`, `sammel`, `feier` and `steuer` end in an unstressed syllable and take `n` instead of `en`. Stems such as `studier` and `frier`, where a single vowel comes before, don't.
+fn ends_with_schwa_syllable(stem: &str) -> bool {
+    if !stem.ends_with("el") && !stem.ends_with("er") {
+        return false;
+    }
+    let before = &stem[..stem.len() - 2];
+    DIPHTHONGS
+        .iter()
+        .any(|diphthong| before.ends_with(diphthong))
+        || !before.ends_with(&['a', 'e', 'i', 'o', 'u', 'ä', 'ö', 'ü'] as &[_])
+}
+
+fn first_person_singular(stem: &str) -> String {
+    if ends_with_schwa_syllable(stem) && stem.ends_with("el") {
+        format!("{}le", &stem[..stem.len() - 2])
+    } else {
+        format!("{}e", stem)
+    }
+}
+
+fn present(verb: &Verb) -> Vec<String> {
+    let stem = verb.stem;
+    let (second, third) = if verb.changed_stem == stem {
+        if needs_e(stem) {
+            (format!("{}est", stem), format!("{}et", stem))
+        } else if ends_with_sibilant(stem) {
+            (format!("{}t", stem), format!("{}t", stem))
+        } else {
+            (format!("{}st", stem), format!("{}t", stem))
+        }
+    } else {
+        // A changed stem takes no `e` before the endings, and the ending `t` merges with a final `t`, as in `hältst` and `hält`.
+        let changed_stem = verb.changed_stem;
+        (
+            if ends_with_sibilant(changed_stem) {
+                format!("{}t", changed_stem)
+            } else {
+                format!("{}st", changed_stem)
+            },
+            if changed_stem.ends_with('t') {
+                changed_stem.to_string()
+            } else {
+                format!("{}t", changed_stem)
+            },
+        )
+    };
+    vec![
+        first_person_singular(stem),
+        second,
+        third,
+        infinitive(stem),
+        if needs_e(stem) {
+            format!("{}et", stem)
+        } else {
+            format!("{}t", stem)
+        },
+        infinitive(stem),
+    ]
+}
+
+// Returns the past participle, or nothing if the stem starts with what may be an inseparable prefix, which would leave out `ge`. Such a stem can't always be told apart from a stem that only starts with the same letters.
+fn regular_past_participle(stem: &str) -> Option<String> {
+    if INSEPARABLE_PREFIXES
+        .iter()
+        .any(|prefix| starts_with_inseparable_prefix(stem, prefix))
+    {
+        return None;
+    }
+    let prefix = if stem.ends_with("ier") && !stem.ends_with("eier") {
+        ""
+    } else {
+        "ge"
+    };
+    Some(if needs_e(stem) {
+        format!("{}{}et", prefix, stem)
+    } else {
+        format!("{}{}t", prefix, stem)
+    })
+}
+
+// The prefix is not taken to be a prefix when the rest of the stem is too short to be a stem of its own, when it starts with a double consonant as in `bettel` and `besser`, or when its first letter forms a diphthong with the prefix as in `beicht` and `geig`.
+fn starts_with_inseparable_prefix(stem: &str, prefix: &str) -> bool {
+    if stem.len() <= prefix.len() + 2 || !stem.starts_with(prefix) {
+        return false;
+    }
+    let mut characters = stem[prefix.len()..].chars();
+    let first = characters.next();
+    if first == characters.next() {
+        return false;
+    }
+    let pair: String = prefix
+        .chars()
+        .next_back()
+        .into_iter()
+        .chain(first)
+        .collect();
+    !DIPHTHONGS.iter().any(|diphthong| *diphthong == pair)
+}
+
+fn strong_preterite(stem: &str) -> Vec<String> {
+    // A preterite stem ending in `e`, as in `brachte`, takes the endings of the weak preterite.
+    if stem.ends_with('e') {
+        return weak_endings(stem);
+    }
+    vec![
+        stem.to_string(),
+        if needs_e(stem) || ends_with_sibilant(stem) {
+            format!("{}est", stem)
+        } else {
+            format!("{}st", stem)
+        },
+        stem.to_string(),
+        format!("{}en", stem),
+        if needs_e(stem) {
+            format!("{}et", stem)
+        } else {
+            format!("{}t", stem)
+        },
+        format!("{}en", stem),
+    ]
+}
+
+fn subjunctive_i(stem: &str) -> Vec<String> {
+    vec![
+        first_person_singular(stem),
+        format!("{}est", stem),
+        format!("{}e", stem),
+        infinitive(stem),
+        format!("{}et", stem),
+        infinitive(stem),
+    ]
+}
+
+fn subjunctive_ii(stem: &str) -> Vec<String> {
+    if stem.ends_with('e') {
+        weak_endings(stem)
+    } else {
+        weak_endings(&format!("{}e", stem))
+    }
+}
+
+fn weak_endings(stem: &str) -> Vec<String> {
+    vec![
+        stem.to_string(),
+        format!("{}st", stem),
+        stem.to_string(),
+        format!("{}n", stem),
+        format!("{}t", stem),
+        format!("{}n", stem),
+    ]
+}
+
+fn weak_preterite(stem: &str) -> Vec<String> {
+    weak_endings(&if needs_e(stem) {
+        format!("{}ete", stem)
+    } else {
+        format!("{}te", stem)
+    })
+}
+
+fn with_prefix(form: &str, prefix: &str) -> String {
+    if prefix.is_empty() {
+        form.to_string()
+    } else {
+        format!("{} {}", form, prefix)
+    }
+}
+
+fn push_imperative(forms: &mut Vec<::InflectedForm>, verb: &Verb) {
+    let stem = verb.stem;
+    // Verbs that change `e` to `i` in the present also do so in the imperative singular, as in `gib` and `lies`, but other changes of the stem don't carry over to the imperative.
+    let singular = if verb.changed_stem.contains('i') && !stem.contains('i') {
+        vec![verb.changed_stem.to_string()]
+    } else if needs_e(stem) || ends_with_schwa_syllable(stem) {
+        vec![first_person_singular(stem)]
+    } else {
+        vec![stem.to_string(), format!("{}e", stem)]
+    };
+    let plural = if needs_e(stem) {
+        format!("{}et", stem)
+    } else {
+        format!("{}t", stem)
+    };
+    for (number, form) in singular
+        .into_iter()
+        .map(|form| (::Number::Singular, form))
+        .chain(::std::iter::once((::Number::Plural, plural)))
+    {
+        forms.push(::InflectedForm {
+            features: ::Features {
+                mood: Some(::Mood::Imperative),
+                number: Some(number),
+                person: Some(::Person::Second),
+                tense: Some(::Tense::Present),
+                voice: Some(::Voice::Active),
+                ..Default::default()
+            },
+            form: with_prefix(&form, verb.prefix),
+        });
+    }
+}
+
+type Tenses = [Option<(::Mood, ::Tense)>; 4];
+
+// The tenses formed with each of the forms of the auxiliary verb, in the same order as the forms.
+const SIMPLE: Tenses = [
+    Some((::Mood::Indicative, ::Tense::Present)),
+    Some((::Mood::Indicative, ::Tense::Preterite)),
+    Some((::Mood::SubjunctiveI, ::Tense::Present)),
+    Some((::Mood::SubjunctiveIi, ::Tense::Preterite)),
+];
+
+const PERFECT: Tenses = [
+    Some((::Mood::Indicative, ::Tense::Perfect)),
+    Some((::Mood::Indicative, ::Tense::Pluperfect)),
+    Some((::Mood::SubjunctiveI, ::Tense::Perfect)),
+    Some((::Mood::SubjunctiveIi, ::Tense::Pluperfect)),
+];
+
+const FUTURE: Tenses = [
+    Some((::Mood::Indicative, ::Tense::Future)),
+    None,
+    Some((::Mood::SubjunctiveI, ::Tense::Future)),
+    Some((::Mood::SubjunctiveIi, ::Tense::Future)),
+];
+
+const FUTURE_PERFECT: Tenses = [
+    Some((::Mood::Indicative, ::Tense::FuturePerfect)),
+    None,
+    Some((::Mood::SubjunctiveI, ::Tense::FuturePerfect)),
+    Some((::Mood::SubjunctiveIi, ::Tense::FuturePerfect)),
+];
+
+fn push_compound(
+    forms: &mut Vec<::InflectedForm>,
+    voice: ::Voice,
+    auxiliary: &[[&str; 6]; 4],
+    complement: &str,
+    tenses: Tenses,
+) {
+    for (auxiliary, tense) in auxiliary.iter().zip(&tenses) {
+        if let Some((mood, tense)) = *tense {
+            push_finite(
+                forms,
+                mood,
+                tense,
+                voice,
+                auxiliary
+                    .iter()
+                    .map(|auxiliary| format!("{} {}", auxiliary, complement))
+                    .collect(),
+            );
+        }
+    }
+}
+
+fn push_finite(
+    forms: &mut Vec<::InflectedForm>,
+    mood: ::Mood,
+    tense: ::Tense,
+    voice: ::Voice,
+    forms_of_tense: Vec<String>,
+) {
+    for (&(person, number), form) in PERSONS.iter().zip(forms_of_tense) {
+        forms.push(::InflectedForm {
+            features: ::Features {
+                mood: Some(mood),
+                number: Some(number),
+                person: Some(person),
+                tense: Some(tense),
+                voice: Some(voice),
+                ..Default::default()
+            },
+            form,
+        });
+    }
+}
+
+fn push_non_finite(forms: &mut Vec<::InflectedForm>, non_finite: ::NonFinite, form: String) {
+    forms.push(::InflectedForm {
+        features: ::Features {
+            non_finite: Some(non_finite),
+            ..Default::default()
+        },
+        form,
+    });
+}
+
+// The passive voices are formed with the past participle and `werden` for the process passive or `sein` for the state passive, which in turn are conjugated in all tenses.
+fn push_passive(
+    forms: &mut Vec<::InflectedForm>,
+    voice: ::Voice,
+    auxiliary: &[[&str; 6]; 4],
+    auxiliary_infinitive: &str,
+    auxiliary_past_participle: &str,
+    past_participle: &str,
+) {
+    push_compound(forms, voice, auxiliary, past_participle, SIMPLE);
+    push_compound(
+        forms,
+        voice,
+        &SEIN,
+        &format!("{} {}", past_participle, auxiliary_past_participle),
+        PERFECT,
+    );
+    push_compound(
+        forms,
+        voice,
+        &WERDEN,
+        &format!("{} {}", past_participle, auxiliary_infinitive),
+        FUTURE,
+    );
+    push_compound(
+        forms,
+        voice,
+        &WERDEN,
+        &format!("{} {} sein", past_participle, auxiliary_past_participle),
+        FUTURE_PERFECT,
+    );
+}
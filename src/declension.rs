@@ -0,0 +1,239 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+const CASES: [::Case; 4] = [
+    ::Case::Nominative,
+    ::Case::Genitive,
+    ::Case::Dative,
+    ::Case::Accusative,
+];
+
+// The endings of attributive adjectives by case in the order of `CASES`, each with the endings for masculine, feminine and neuter singular followed by plural.
+const ENDINGS: [(::Declension, [[&str; 4]; 4]); 3] = [
+    (
+        ::Declension::Strong,
+        [
+            ["er", "e", "es", "e"],
+            ["en", "er", "en", "er"],
+            ["em", "er", "em", "en"],
+            ["en", "e", "es", "e"],
+        ],
+    ),
+    (
+        ::Declension::Weak,
+        [
+            ["e", "e", "e", "en"],
+            ["en", "en", "en", "en"],
+            ["en", "en", "en", "en"],
+            ["en", "e", "e", "en"],
+        ],
+    ),
+    (
+        ::Declension::Mixed,
+        [
+            ["er", "e", "es", "en"],
+            ["en", "en", "en", "en"],
+            ["en", "en", "en", "en"],
+            ["en", "e", "es", "en"],
+        ],
+    ),
+];
+
+const GENDERS: [Option<::Gender>; 4] = [
+    Some(::Gender::Masculine),
+    Some(::Gender::Feminine),
+    Some(::Gender::Neuter),
+    None,
+];
+
+// The parameters are `1` for the positive, `2` for the comparative and `3` for the superlative without ending, as in `schön`, `schöner` and `schönst`. The optional parameter `Stamm` gives the stem of the positive before endings where it differs from the positive, as in `dunkl`. Adjectives without comparison leave out parameters `2` and `3`.
+pub fn parse_adjective_declension<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+) -> Option<Vec<::InflectedForm>> {
+    let parameters = ::flexion::parse_parameters(context, parameters, &["1", "2", "3", "Stamm"]);
+    let positive = match parameters.get("1") {
+        None => {
+            ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+            return None;
+        }
+        Some(positive) => positive,
+    };
+    let mut forms = vec![];
+    for (degree, predicative, stem) in [
+        (
+            ::Degree::Positive,
+            Some(positive.to_string()),
+            Some(parameters.get("Stamm").unwrap_or(positive)),
+        ),
+        (
+            ::Degree::Comparative,
+            parameters.get("2").map(|form| form.to_string()),
+            parameters.get("2"),
+        ),
+        (
+            ::Degree::Superlative,
+            parameters.get("3").map(|form| format!("am {}en", form)),
+            parameters.get("3"),
+        ),
+    ] {
+        if let Some(predicative) = predicative {
+            forms.push(::InflectedForm {
+                features: ::Features {
+                    degree: Some(degree),
+                    ..Default::default()
+                },
+                form: predicative,
+            });
+        }
+        if let Some(stem) = stem {
+            for &(declension, ref endings) in &ENDINGS {
+                for (&case, endings) in CASES.iter().zip(endings) {
+                    for (&gender, ending) in GENDERS.iter().zip(endings) {
+                        forms.push(::InflectedForm {
+                            features: ::Features {
+                                case: Some(case),
+                                declension: Some(declension),
+                                degree: Some(degree),
+                                gender,
+                                number: Some(if gender.is_none() {
+                                    ::Number::Plural
+                                } else {
+                                    ::Number::Singular
+                                }),
+                                ..Default::default()
+                            },
+                            form: with_ending(stem, ending),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    Some(forms)
+}
+
+// The lemma is the nominative singular. The optional parameter `Genitiv` gives the genitive singular of masculine and neuter nouns. Without it, the genitive singular is formed with `es` after a sibilant and with `s` otherwise, except that a noun of one syllable may take either and its genitive singular is left out. The parameter `Plural` gives the nominative plural, which is required for strong nouns that have a plural and overrides the plural of weak nouns.
+pub fn parse_noun_declension<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    lemma: &str,
+    gender: ::Gender,
+    weak: bool,
+    parameters: &[::Parameter<'a>],
+) -> Option<Vec<::InflectedForm>> {
+    let parameters = ::flexion::parse_parameters(context, parameters, &["Genitiv", "Plural"]);
+    if lemma.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+        return None;
+    }
+    let weak_form = if lemma.ends_with('e') || lemma.ends_with("el") || lemma.ends_with("er") {
+        format!("{}n", lemma)
+    } else {
+        format!("{}en", lemma)
+    };
+    let singular = if weak && gender == ::Gender::Masculine {
+        [
+            Some(lemma.to_string()),
+            // A few weak nouns, such as `Name` and `Gedanke`, take `ns` in the genitive singular.
+            Some(match parameters.get("Genitiv") {
+                None => weak_form.clone(),
+                Some(genitive) => genitive.to_string(),
+            }),
+            Some(weak_form.clone()),
+            Some(weak_form.clone()),
+        ]
+    } else if gender == ::Gender::Feminine {
+        [
+            Some(lemma.to_string()),
+            Some(lemma.to_string()),
+            Some(lemma.to_string()),
+            Some(lemma.to_string()),
+        ]
+    } else {
+        [
+            Some(lemma.to_string()),
+            match parameters.get("Genitiv") {
+                None if lemma.ends_with(&['s', 'ß', 'x', 'z'] as &[_])
+                    || lemma.ends_with("sch") =>
+                {
+                    Some(format!("{}es", lemma))
+                }
+                // A noun of one syllable, such as `Tag`, may take either `s` or `es`.
+                None if count_syllables(lemma) == 1 => {
+                    ::add_warning(context, template_node, ::WarningMessage::FormAmbiguous);
+                    None
+                }
+                None => Some(format!("{}s", lemma)),
+                Some(genitive) => Some(genitive.to_string()),
+            },
+            Some(lemma.to_string()),
+            Some(lemma.to_string()),
+        ]
+    };
+    let plural = match parameters.get("Plural") {
+        None if weak => Some(weak_form),
+        None => None,
+        Some(plural) => Some(plural.to_string()),
+    }
+    .map(|plural| {
+        // The dative plural ends in `n` except after `n` or `s`.
+        let dative = if plural.ends_with(&['n', 's'] as &[_]) {
+            plural.clone()
+        } else {
+            format!("{}n", plural)
+        };
+        [
+            Some(plural.clone()),
+            Some(plural.clone()),
+            Some(dative),
+            Some(plural),
+        ]
+    });
+    let mut forms = vec![];
+    for (number, forms_of_number) in ::std::iter::once((::Number::Singular, singular))
+        .chain(plural.map(|plural| (::Number::Plural, plural)))
+    {
+        for (&case, form) in CASES.iter().zip(forms_of_number.iter()) {
+            let form = match form {
+                None => continue,
+                Some(form) => form,
+            };
+            forms.push(::InflectedForm {
+                features: ::Features {
+                    case: Some(case),
+                    gender: Some(gender),
+                    number: Some(number),
+                    ..Default::default()
+                },
+                form: form.clone(),
+            });
+        }
+    }
+    Some(forms)
+}
+
+// Counts the groups of consecutive vowels, each of which is taken to be one syllable.
+fn count_syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_is_vowel = false;
+    for character in word.chars().flat_map(char::to_lowercase) {
+        let is_vowel = "aeiouyäöü".contains(character);
+        if is_vowel && !previous_is_vowel {
+            count += 1;
+        }
+        previous_is_vowel = is_vowel;
+    }
+    count
+}
+
+fn with_ending(stem: &str, ending: &str) -> String {
+    // A stem ending in `e`, as in `leise`, doesn't repeat the `e` of the ending.
+    if stem.ends_with('e') {
+        format!("{}{}", stem, &ending[1..])
+    } else {
+        format!("{}{}", stem, ending)
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_definitions<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter],
+    nodes: &[::Node<'a>],
+    output: &mut Option<Vec<::Definition<'a>>>,
+) -> usize {
+    ::parse_list_generic(
+        context,
+        template_node,
+        parameters,
+        nodes,
+        output,
+        |context, items| {
+            let mut definitions: Vec<::Definition> = vec![];
+            for item in items {
+                if item.type_ != ::Details {
+                    ::add_warning(context, item, ::WarningMessage::Unrecognized);
+                    continue;
+                }
+                if let Some(definition) = parse_definition(context, item, None) {
+                    // A sub-sense written at the same level as its parent, such as `[2a]` following `[2]`, is nested under the parent.
+                    if let Some(::Sense {
+                        letter: Some(_),
+                        number,
+                    }) = definition.sense
+                    {
+                        if let Some(parent) = definitions.last_mut() {
+                            if parent.sense
+                                == Some(::Sense {
+                                    letter: None,
+                                    number,
+                                })
+                            {
+                                parent.subsenses.push(definition);
+                                continue;
+                            }
+                        }
+                    }
+                    definitions.push(definition);
+                }
+            }
+            definitions
+        },
+    )
+}
+
+fn parse_definition<'a>(
+    context: &mut ::Context<'a>,
+    list_item: &::DefinitionListItem<'a>,
+    parent: Option<&::Sense>,
+) -> Option<::Definition<'a>> {
+    if list_item.nodes.is_empty() {
+        ::add_warning(context, list_item, ::WarningMessage::Empty);
+        return None;
+    }
+    let nested_index = list_item
+        .nodes
+        .iter()
+        .position(|node| matches!(node, ::Node::DefinitionList { .. }))
+        .unwrap_or(list_item.nodes.len());
+    let mut gloss = ::list::parse_list_item(context, &list_item.nodes[..nested_index], true);
+    let sense = match ::senses::parse_leading_senses(&mut gloss, parent) {
+        Some(ref senses) if senses.len() == 1 => Some(senses[0]),
+        _ => {
+            ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+            None
+        }
+    };
+    let mut labels = vec![];
+    while let Some(::Flowing::Context {
+        labels: context_labels,
+    }) = gloss.first_mut()
+    {
+        labels.append(context_labels);
+        gloss.remove(0);
+        // Consecutive label templates may be separated by a comma, a semicolon or a colon, as in `{{ugs.}}, {{va.}}`.
+        let separator = match (gloss.first(), gloss.get(1)) {
+            (Some(::Flowing::Text { value }), Some(::Flowing::Context { .. })) => value
+                .trim_matches(|character: char| {
+                    character.is_whitespace() || ",;:".contains(character)
+                })
+                .is_empty(),
+            _ => false,
+        };
+        if separator {
+            gloss.remove(0);
+        }
+        if let Some(::Flowing::Text { value }) = gloss.first_mut() {
+            let start = value.len() - value.trim_start().len();
+            *value = ::slice_cow(value, start, value.len());
+        }
+        if let Some(::Flowing::Text { value }) = gloss.first() {
+            if value.is_empty() {
+                gloss.remove(0);
+            }
+        }
+    }
+    let mut subsenses = vec![];
+    for node in &list_item.nodes[nested_index..] {
+        match node {
+            ::Node::DefinitionList { items, .. } => {
+                for item in items {
+                    if item.type_ == ::Details {
+                        subsenses.extend(parse_definition(context, item, sense.as_ref()));
+                    } else {
+                        ::add_warning(context, item, ::WarningMessage::Unrecognized);
+                    }
+                }
+            }
+            _ => ::add_warning(context, node, ::WarningMessage::Unrecognized),
+        }
+    }
+    Some(::Definition {
+        gloss,
+        labels,
+        sense,
+        subsenses,
+    })
+}
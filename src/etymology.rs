@@ -0,0 +1,371 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_etymology<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter],
+    nodes: &[::Node<'a>],
+    output: &mut Option<Vec<::Etymology<'a>>>,
+) -> usize {
+    ::parse_list_items_generic(
+        context,
+        template_node,
+        parameters,
+        nodes,
+        output,
+        |context, list_item| {
+            if list_item.nodes.is_empty() {
+                ::add_warning(context, list_item, ::WarningMessage::Empty);
+                None
+            } else {
+                let mut content = ::list::parse_list_item(context, &list_item.nodes, true);
+                let senses = ::senses::parse_optional_senses(context, list_item, &mut content);
+                let steps = parse_steps(&content, context.language).unwrap_or_default();
+                Some(::Etymology {
+                    content,
+                    senses,
+                    steps,
+                })
+            }
+        },
+    )
+}
+
+// Grammatical term naming what a cited form is.
+#[derive(Clone, Copy)]
+pub enum Marker {
+    LinkingElement,
+    Pos(::Pos),
+}
+
+#[derive(Default)]
+struct State<'a> {
+    // The index of the first step of the current clause.
+    clause_start: usize,
+
+    // The form being cited while in italics.
+    form: Option<::Cow<'a, str>>,
+
+    // The gloss while in quotation marks.
+    gloss: Option<::Cow<'a, str>>,
+
+    // The language of the entry, whose earlier stages the word may be inherited from.
+    entry_language: Option<::Language>,
+
+    language: Option<::LanguageReference<'a>>,
+
+    // Whether a linking element was named since the last form, as in `sowie dem [[Fugenelement]] [[-s]]`, in which case the next form is the linking element rather than a step.
+    linking_element: bool,
+
+    // Whether a negation was found in the current clause, as in `das keine Ableitung von ''x'' ist`, in which case keywords and forms are skipped until the end of the clause.
+    negated: bool,
+
+    relation: Option<::EtymologyRelation>,
+
+    // Whether the relation was given after the last step, in which case it applies to the language following it rather than being reset by it.
+    relation_is_new: bool,
+
+    // Whether the last word was a preposition naming a source, as in `von {{mhd.}}`.
+    source: bool,
+
+    steps: Vec<Step<'a>>,
+}
+
+// Step whose relation may still be given by a keyword later in the clause, as in `von {{lat.}} ''natio'' entlehnt`.
+struct Step<'a> {
+    form: ::Cow<'a, str>,
+    gloss: Option<::Cow<'a, str>>,
+    language: Option<::LanguageReference<'a>>,
+    relation: Option<::EtymologyRelation>,
+}
+
+// Classifies an etymology written in the usual way, such as `von {{mhd.}} ''hūs'', {{ahd.}} ''hūs'' „Haus“` or `Entlehnung aus {{fr.}} ''garage''`. Each cited form becomes a step with the last language given before it. Forms are cited in italics, or as links following a keyword, as in `Determinativkompositum aus [[Haus]] und [[Tür]]`, where links to grammatical terms such as `[[Fugenelement]]` are not forms. The form following a linking element, as in `sowie dem [[Fugenelement]] [[-s]]`, is not a step. The relation is given by a keyword such as “Entlehnung” or “Kompositum”, matched as a whole word or the end of a compound word, and applies to the forms following it as well as to the forms before it in the same clause, as in `im 16. Jahrhundert von {{lat.}} ''natio'' entlehnt`. An earlier stage of the language of the entry following `von`, `aus` or `über`, as in `von {{mhd.}} ''hūs''`, or following a step inherited from such a stage, as `{{ahd.}} ''hūs''` does in the same etymology, gives the relation `Inherited`. A new language ends the relation unless the keyword comes after the last step. Returns `None` if a form is found that no relation applies to, as in `von {{lat.}} ''natio''`, if anything else than text, formatting, languages, links and terms is found, or if no form is found.
+fn parse_steps<'a>(
+    content: &[::Flowing<'a>],
+    entry_language: Option<::Language>,
+) -> Option<Vec<::EtymologyStep<'a>>> {
+    let mut state = State {
+        entry_language,
+        ..Default::default()
+    };
+    for item in content {
+        match item {
+            ::Flowing::Bold
+            | ::Flowing::Comment
+            | ::Flowing::QualityControl
+            | ::Flowing::Reference
+            | ::Flowing::SuperscriptEnd
+            | ::Flowing::SuperscriptStart => {}
+            ::Flowing::Italic => match state.form.take() {
+                None => state.form = Some(::Cow::Borrowed("")),
+                Some(form) => state.push_step(form, None),
+            },
+            ::Flowing::Language { language } | ::Flowing::LanguageAdjective { language } => {
+                if state.is_earlier_stage(language)
+                    && (state.source || state.relation == Some(::EtymologyRelation::Inherited))
+                {
+                    state.relation = Some(::EtymologyRelation::Inherited);
+                } else if !state.relation_is_new {
+                    state.relation = None;
+                }
+                state.language = Some(language.clone());
+                state.source = false;
+            }
+            ::Flowing::Link { target, text } => {
+                if state.form.is_none()
+                    && state.gloss.is_none()
+                    && state.relation.is_some()
+                    && parse_relation(&text.to_lowercase()).is_none()
+                    && !is_marker(text)
+                {
+                    state.push_step(target.clone(), None);
+                } else {
+                    state.parse_text(text.clone());
+                }
+            }
+            ::Flowing::Term { language, term, .. } => {
+                state.push_step(term.clone(), Some(language.clone()))
+            }
+            ::Flowing::Text { value } => {
+                let mut start = 0;
+                for (index, character) in value.char_indices() {
+                    if state.form.is_some() || !['„', '“'].contains(&character) {
+                        continue;
+                    }
+                    state.parse_text(::slice_cow(value, start, index));
+                    start = index + character.len_utf8();
+                    match (character, state.gloss.take()) {
+                        ('„', None) => state.gloss = Some(::Cow::Borrowed("")),
+                        ('“', Some(gloss)) => {
+                            let gloss = ::trim_cow(&gloss);
+                            if let Some(step) = state.steps.last_mut() {
+                                if step.gloss.is_none() && !gloss.is_empty() {
+                                    step.gloss = Some(gloss);
+                                }
+                            }
+                        }
+                        _ => return None,
+                    }
+                }
+                state.parse_text(::slice_cow(value, start, value.len()));
+            }
+            _ => return None,
+        }
+    }
+    if state.form.is_some() || state.gloss.is_some() || state.steps.is_empty() {
+        return None;
+    }
+    state
+        .steps
+        .into_iter()
+        .map(|step| {
+            Some(::EtymologyStep {
+                form: step.form,
+                gloss: step.gloss,
+                language: step.language,
+                relation: step.relation?,
+            })
+        })
+        .collect()
+}
+
+impl<'a> State<'a> {
+    fn is_earlier_stage(&self, language: &::LanguageReference) -> bool {
+        match (self.entry_language, language.language()) {
+            (Some(entry_language), Some(language)) => is_earlier_stage(language, entry_language),
+            _ => false,
+        }
+    }
+
+    fn parse_text(&mut self, text: ::Cow<'a, str>) {
+        if let Some(target) = self.form.as_mut().or(self.gloss.as_mut()) {
+            if target.is_empty() {
+                *target = text;
+            } else {
+                target.to_mut().push_str(&text);
+            }
+            return;
+        }
+        let text = text.to_lowercase();
+        for (index, clause) in text.split(&[',', '.', ':', ';'] as &[_]).enumerate() {
+            if index > 0 {
+                self.clause_start = self.steps.len();
+                self.negated = false;
+                self.source = false;
+            }
+            for word in clause.split(|character: char| !character.is_alphabetic()) {
+                match word {
+                    "" | "dem" | "den" | "der" | "des" => continue,
+                    "aus" | "über" | "von" | "vom" => {
+                        self.source = true;
+                        continue;
+                    }
+                    "kein" | "keine" | "keinem" | "keinen" | "keiner" | "nicht" => {
+                        self.negated = true
+                    }
+                    _ if self.negated => {}
+                    _ if is_linking_element(word) => self.linking_element = true,
+                    _ => {
+                        if let Some(relation) = parse_relation(word) {
+                            self.set_relation(relation);
+                        }
+                    }
+                }
+                self.source = false;
+            }
+        }
+    }
+
+    // A keyword following steps without a relation in the same clause applies to them, and then doesn't carry over to the next language.
+    fn set_relation(&mut self, relation: ::EtymologyRelation) {
+        self.relation = Some(relation);
+        self.relation_is_new = true;
+        for step in &mut self.steps[self.clause_start..] {
+            if step.relation.is_none() {
+                step.relation = Some(relation);
+                self.relation_is_new = false;
+            }
+        }
+    }
+
+    // Punctuation following a form in italics, as in `''nation,''`, is not part of the form.
+    fn push_step(&mut self, form: ::Cow<'a, str>, language: Option<::LanguageReference<'a>>) {
+        let form = ::trim_cow(&form);
+        let end = form
+            .trim_end_matches(&[',', '.', ':', ';', '!', '?'] as &[_])
+            .trim_end()
+            .len();
+        let form = ::slice_cow(&form, 0, end);
+        if form.is_empty() || self.negated {
+            return;
+        }
+        if self.linking_element {
+            self.linking_element = false;
+            return;
+        }
+        let language = language.or_else(|| self.language.clone());
+        self.relation_is_new = false;
+        self.source = false;
+        self.steps.push(Step {
+            form,
+            gloss: None,
+            language,
+            relation: self.relation,
+        });
+    }
+}
+
+// Whether the language is an earlier stage or an ancestor of the other language, from which words are inherited.
+fn is_earlier_stage(language: ::Language, of: ::Language) -> bool {
+    match of {
+        ::Language::De => [
+            ::Language::Gmh,
+            ::Language::Goh,
+            ::Language::Gem,
+            ::Language::Ine,
+        ],
+        ::Language::En => [
+            ::Language::Enm,
+            ::Language::Ang,
+            ::Language::Gem,
+            ::Language::Ine,
+        ],
+        ::Language::Fr => [
+            ::Language::Frm,
+            ::Language::Fro,
+            ::Language::La,
+            ::Language::Ine,
+        ],
+        ::Language::Nds => [
+            ::Language::Gml,
+            ::Language::Osx,
+            ::Language::Gem,
+            ::Language::Ine,
+        ],
+        ::Language::Nl => [
+            ::Language::Dum,
+            ::Language::Odt,
+            ::Language::Gem,
+            ::Language::Ine,
+        ],
+        _ => return false,
+    }
+    .contains(&language)
+}
+
+// The keyword may be preceded by the first part of a compound word, as in `Determinativkompositum`, and followed by an inflectional ending, as in `entlehnten`.
+fn parse_relation(word: &str) -> Option<::EtymologyRelation> {
+    [
+        ("abgeleitet", ::EtymologyRelation::Derived),
+        ("ableitung", ::EtymologyRelation::Derived),
+        ("derivat", ::EtymologyRelation::Derived),
+        ("entlehnt", ::EtymologyRelation::Borrowed),
+        ("entlehnung", ::EtymologyRelation::Borrowed),
+        ("erbwort", ::EtymologyRelation::Inherited),
+        ("ererbt", ::EtymologyRelation::Inherited),
+        ("komposition", ::EtymologyRelation::Compound),
+        ("kompositum", ::EtymologyRelation::Compound),
+        ("lehnwort", ::EtymologyRelation::Borrowed),
+        ("übernommen", ::EtymologyRelation::Borrowed),
+        ("zusammengesetzt", ::EtymologyRelation::Compound),
+        ("zusammensetzung", ::EtymologyRelation::Compound),
+    ]
+    .iter()
+    .find(|(keyword, _)| match word.find(keyword) {
+        None => false,
+        Some(index) => {
+            ["", "e", "em", "en", "er", "es", "s"].contains(&&word[index + keyword.len()..])
+        }
+    })
+    .map(|(_, relation)| *relation)
+}
+
+// Whether the text names a part of speech or linking element, as the text of a link to a grammatical term such as `[[Fugenelement]]` does.
+fn is_marker(text: &str) -> bool {
+    parse_markers(text).is_some()
+}
+
+// Whether the text names a linking element, as `Fugenelement` does.
+fn is_linking_element(text: &str) -> bool {
+    matches!(parse_markers(text), Some((Marker::LinkingElement, _)))
+}
+
+// Returns the last part of speech or linking element named in the text if any, and whether it's named in the plural. The word `Pronomen`, which is the same in both numbers, is taken to be singular.
+pub fn parse_markers(text: &str) -> Option<(Marker, bool)> {
+    text.split(|character: char| !character.is_alphabetic())
+        .filter_map(|word| {
+            Some(match &word.to_lowercase() as _ {
+                "adjektiv" | "adjektivs" => (Marker::Pos(::Pos::Adjective), false),
+                "adjektive" | "adjektiven" => (Marker::Pos(::Pos::Adjective), true),
+                "adverb" | "adverbs" => (Marker::Pos(::Pos::Adverb), false),
+                "adverbien" => (Marker::Pos(::Pos::Adverb), true),
+                "ableitungsmorphem" | "ableitungsmorphems" | "derivatem" | "derivatems"
+                | "suffix" | "suffixes" => (Marker::Pos(::Pos::Suffix), false),
+                "ableitungsmorpheme"
+                | "ableitungsmorphemen"
+                | "derivateme"
+                | "derivatemen"
+                | "suffixe"
+                | "suffixen" => (Marker::Pos(::Pos::Suffix), true),
+                "fugenelement" | "fugenelements" => (Marker::LinkingElement, false),
+                "fugenelemente" | "fugenelementen" => (Marker::LinkingElement, true),
+                "numerale" | "zahlwort" | "zahlworts" => (Marker::Pos(::Pos::Numeral), false),
+                "numeralia" | "zahlwörter" | "zahlwörtern" => (Marker::Pos(::Pos::Numeral), true),
+                "partikel" => (Marker::Pos(::Pos::Particle), false),
+                "partikeln" => (Marker::Pos(::Pos::Particle), true),
+                "präfix" | "präfixes" => (Marker::Pos(::Pos::Prefix), false),
+                "präfixe" | "präfixen" => (Marker::Pos(::Pos::Prefix), true),
+                "präposition" => (Marker::Pos(::Pos::Preposition), false),
+                "präpositionen" => (Marker::Pos(::Pos::Preposition), true),
+                "pronomen" | "pronomens" => (Marker::Pos(::Pos::Pronoun), false),
+                "substantiv" | "substantivs" => (Marker::Pos(::Pos::Noun), false),
+                "substantive" | "substantiven" => (Marker::Pos(::Pos::Noun), true),
+                "verb" | "verbs" => (Marker::Pos(::Pos::Verb), false),
+                "verben" => (Marker::Pos(::Pos::Verb), true),
+                _ => return None,
+            })
+        })
+        .next_back()
+}
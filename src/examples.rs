@@ -65,7 +65,7 @@ fn parse_example<'a>(
                 } else {
                     ::add_warning(context, node, ::WarningMessage::ValueUnrecognized);
                 }
-                while let Some(node) = iterator.next() {
+                for node in iterator.by_ref() {
                     ::add_warning(context, node, ::WarningMessage::Unrecognized);
                 }
                 break;
@@ -78,8 +78,10 @@ fn parse_example<'a>(
         }
     }
     if has_text {
+        let senses = ::senses::parse_optional_senses(context, list_item, &mut example);
         Some(::Example {
             example,
+            senses,
             translation,
         })
     } else {
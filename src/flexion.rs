@@ -0,0 +1,123 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_flexion<'a>(
+    context: &mut ::Context<'a>,
+    lemma: &str,
+    nodes: &[::Node<'a>],
+) -> Vec<::Paradigm<'a>> {
+    let mut paradigms = vec![];
+    for node in nodes {
+        match node {
+            ::Node::Category { .. } | ::Node::Heading { .. } | ::Node::ParagraphBreak { .. } => {}
+            ::Node::Template {
+                name, parameters, ..
+            } => {
+                let name = match ::parse_text(name) {
+                    None => {
+                        ::add_warning(context, node, ::WarningMessage::Unrecognized);
+                        continue;
+                    }
+                    Some(name) => name,
+                };
+                let (pos, forms) = match &name as _ {
+                    "Deutsch Adjektiv regelmäßig" => (
+                        ::Pos::Adjective,
+                        ::declension::parse_adjective_declension(context, node, parameters),
+                    ),
+                    "Deutsch Verb regelmäßig" => (
+                        ::Pos::Verb,
+                        ::conjugation::parse_conjugation(context, node, parameters, false),
+                    ),
+                    "Deutsch Verb unregelmäßig" => (
+                        ::Pos::Verb,
+                        ::conjugation::parse_conjugation(context, node, parameters, true),
+                    ),
+                    _ => match parse_noun_template_name(&name) {
+                        None => {
+                            ::add_warning(context, node, ::WarningMessage::Unrecognized);
+                            continue;
+                        }
+                        Some((gender, weak)) => (
+                            ::Pos::Noun,
+                            ::declension::parse_noun_declension(
+                                context, node, lemma, gender, weak, parameters,
+                            ),
+                        ),
+                    },
+                };
+                if let Some(forms) = forms {
+                    paradigms.push(::Paradigm {
+                        forms,
+                        pos,
+                        template: name,
+                    });
+                }
+            }
+            ::Node::Text { value, .. } if value.trim().is_empty() => {}
+            _ => ::add_warning(context, node, ::WarningMessage::Unrecognized),
+        }
+    }
+    paradigms
+}
+
+fn parse_noun_template_name(name: &str) -> Option<(::Gender, bool)> {
+    Some(match name {
+        "Deutsch Substantiv f schwach" => (::Gender::Feminine, true),
+        "Deutsch Substantiv f stark" => (::Gender::Feminine, false),
+        "Deutsch Substantiv m schwach" => (::Gender::Masculine, true),
+        "Deutsch Substantiv m stark" => (::Gender::Masculine, false),
+        "Deutsch Substantiv n stark" => (::Gender::Neuter, false),
+        _ => return None,
+    })
+}
+
+// Collects the parameters that have one of the given names, with unnamed parameters named by their position. Parameters with an empty value or a dash are treated as missing.
+pub fn parse_parameters<'a>(
+    context: &mut ::Context<'a>,
+    parameters: &[::Parameter<'a>],
+    names: &[&'static str],
+) -> ::HashMap<&'static str, ::Cow<'a, str>> {
+    let mut values = ::HashMap::new();
+    let mut position = 0;
+    for parameter in parameters {
+        let name = match parameter.name {
+            None => {
+                position += 1;
+                position.to_string()
+            }
+            Some(_) => match ::parse_parameter_name(parameter) {
+                None => {
+                    ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                    continue;
+                }
+                Some(name) => name.to_string(),
+            },
+        };
+        let name = match names.iter().find(|known_name| **known_name == name) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                continue;
+            }
+            Some(name) => *name,
+        };
+        if values.contains_key(name) {
+            ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+            continue;
+        }
+        match ::parse_text(&parameter.value) {
+            None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+            Some(value) => {
+                let value = match value {
+                    ::Cow::Borrowed(value) => ::Cow::Borrowed(value.trim()),
+                    ::Cow::Owned(value) => ::Cow::Owned(value.trim().to_string()),
+                };
+                if !["", "-", "–", "—"].contains(&(&value as _)) {
+                    values.insert(name, value);
+                }
+            }
+        }
+    }
+    values
+}
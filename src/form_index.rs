@@ -0,0 +1,247 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+impl ::FormIndex {
+    /// Creates an empty index.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the forms from a parsed page in the namespace `Flexion`.
+    ///
+    /// `title` is the title of the page, with or without the prefix `Flexion:`. All the inflection templates that can be parsed are for German words.
+    pub fn add_flexion(&mut self, title: &str, output: &::FlexionOutput) {
+        let lemma = title.trim_start_matches("Flexion:");
+        for paradigm in &output.paradigms {
+            for form in &paradigm.forms {
+                self.insert(
+                    &form.form,
+                    ::FormIndexEntry {
+                        features: form.features,
+                        language: ::Language::De,
+                        lemma: lemma.to_string(),
+                        pos: paradigm.pos,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Adds the forms from a parsed page.
+    ///
+    /// `title` is the title of the page. The title itself is added as a form of each entry without any features once for each of the parts of speech of the entry, except for entries of inflected forms, for which it's added as a form of the lemma given in the field [`form_of`](struct.PosEntry.html#structfield.form_of) with each of the features given there. If the part of speech of the lemma is not given, the part of speech of the entry is used. The forms given in the fields [`comparison`](struct.PosEntry.html#structfield.comparison), [`noun_inflection`](struct.PosEntry.html#structfield.noun_inflection) and [`verb_overview`](struct.PosEntry.html#structfield.verb_overview) are added with their features.
+    pub fn add_output(&mut self, title: &str, output: &::Output) {
+        for language_entry in &output.language_entries {
+            for pos_entry in &language_entry.pos_entries {
+                if let Some(form_of) = &pos_entry.form_of {
+                    if let Some(lemma) = form_of.lemma.as_ref().or(form_of.target.as_ref()) {
+                        for features in &form_of.features {
+                            self.insert(
+                                title,
+                                ::FormIndexEntry {
+                                    features: *features,
+                                    language: language_entry.language,
+                                    lemma: lemma.to_string(),
+                                    pos: form_of.pos.unwrap_or(pos_entry.pos),
+                                },
+                            );
+                        }
+                    }
+                }
+                match pos_entry.pos {
+                    ::Pos::ConjugatedForm | ::Pos::DeclinedForm => {}
+                    _ => for &pos in &pos_entry.parts_of_speech {
+                        self.insert(
+                            title,
+                            ::FormIndexEntry {
+                                features: Default::default(),
+                                language: language_entry.language,
+                                lemma: title.to_string(),
+                                pos,
+                            },
+                        );
+                    },
+                }
+                let mut add = |form: &str, features| {
+                    self.insert(
+                        form,
+                        ::FormIndexEntry {
+                            features,
+                            language: language_entry.language,
+                            lemma: title.to_string(),
+                            pos: pos_entry.pos,
+                        },
+                    )
+                };
+                if let Some(comparison) = &pos_entry.comparison {
+                    for (degree, forms) in &[
+                        (::Degree::Positive, &comparison.positive),
+                        (::Degree::Comparative, &comparison.comparative),
+                        (::Degree::Superlative, &comparison.superlative),
+                        (::Degree::Superlative, &comparison.predicative_superlative),
+                    ] {
+                        for form in *forms {
+                            add(
+                                &form.form,
+                                ::Features {
+                                    degree: Some(*degree),
+                                    ..Default::default()
+                                },
+                            );
+                        }
+                    }
+                }
+                if let Some(inflection) = &pos_entry.noun_inflection {
+                    let gender = match inflection.genders.as_slice() {
+                        [gender] => Some(*gender),
+                        _ => None,
+                    };
+                    for (case, number, forms) in &[
+                        (
+                            ::Case::Nominative,
+                            ::Number::Singular,
+                            &inflection.nominative_singular,
+                        ),
+                        (
+                            ::Case::Genitive,
+                            ::Number::Singular,
+                            &inflection.genitive_singular,
+                        ),
+                        (
+                            ::Case::Dative,
+                            ::Number::Singular,
+                            &inflection.dative_singular,
+                        ),
+                        (
+                            ::Case::Accusative,
+                            ::Number::Singular,
+                            &inflection.accusative_singular,
+                        ),
+                        (
+                            ::Case::Nominative,
+                            ::Number::Plural,
+                            &inflection.nominative_plural,
+                        ),
+                        (
+                            ::Case::Genitive,
+                            ::Number::Plural,
+                            &inflection.genitive_plural,
+                        ),
+                        (::Case::Dative, ::Number::Plural, &inflection.dative_plural),
+                        (
+                            ::Case::Accusative,
+                            ::Number::Plural,
+                            &inflection.accusative_plural,
+                        ),
+                    ] {
+                        for form in *forms {
+                            add(
+                                &form.form,
+                                ::Features {
+                                    case: Some(*case),
+                                    gender,
+                                    number: Some(*number),
+                                    ..Default::default()
+                                },
+                            );
+                        }
+                    }
+                }
+                if let Some(overview) = &pos_entry.verb_overview {
+                    for form in &overview.past_participle {
+                        add(
+                            &form.form,
+                            ::Features {
+                                non_finite: Some(::NonFinite::PastParticiple),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                    for (mood, tense, person, number, forms) in &[
+                        (
+                            ::Mood::Indicative,
+                            ::Tense::Present,
+                            ::Person::First,
+                            ::Number::Singular,
+                            &overview.present_first_singular,
+                        ),
+                        (
+                            ::Mood::Indicative,
+                            ::Tense::Present,
+                            ::Person::Second,
+                            ::Number::Singular,
+                            &overview.present_second_singular,
+                        ),
+                        (
+                            ::Mood::Indicative,
+                            ::Tense::Present,
+                            ::Person::Third,
+                            ::Number::Singular,
+                            &overview.present_third_singular,
+                        ),
+                        (
+                            ::Mood::Indicative,
+                            ::Tense::Preterite,
+                            ::Person::First,
+                            ::Number::Singular,
+                            &overview.preterite_first_singular,
+                        ),
+                        (
+                            ::Mood::SubjunctiveIi,
+                            ::Tense::Preterite,
+                            ::Person::First,
+                            ::Number::Singular,
+                            &overview.subjunctive_ii_first_singular,
+                        ),
+                        (
+                            ::Mood::Imperative,
+                            ::Tense::Present,
+                            ::Person::Second,
+                            ::Number::Singular,
+                            &overview.imperative_singular,
+                        ),
+                        (
+                            ::Mood::Imperative,
+                            ::Tense::Present,
+                            ::Person::Second,
+                            ::Number::Plural,
+                            &overview.imperative_plural,
+                        ),
+                    ] {
+                        for form in *forms {
+                            add(
+                                &form.form,
+                                ::Features {
+                                    mood: Some(*mood),
+                                    number: Some(*number),
+                                    person: Some(*person),
+                                    tense: Some(*tense),
+                                    voice: Some(::Voice::Active),
+                                    ..Default::default()
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the lemmas and features of a form, or an empty slice if the form is not in the index.
+    #[must_use]
+    pub fn get(&self, form: &str) -> &[::FormIndexEntry] {
+        self.forms
+            .get(form)
+            .map(|entries| entries as &[_])
+            .unwrap_or(&[])
+    }
+
+    fn insert(&mut self, form: &str, entry: ::FormIndexEntry) {
+        let entries = self.forms.entry(form.to_string()).or_default();
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+}
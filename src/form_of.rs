@@ -0,0 +1,205 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_base_form_reference<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Option<::Cow<'a, str>>,
+) {
+    if output.is_some() {
+        ::add_warning(context, template_node, ::WarningMessage::Duplicate);
+        return;
+    }
+    // The optional second parameter is the text to display for the link to the base form.
+    if let [target @ ::Parameter { name: None, .. }]
+    | [target @ ::Parameter { name: None, .. }, ::Parameter { name: None, .. }] = parameters
+    {
+        if let Some(target) = ::parse_text_not_empty(&target.value) {
+            *output = Some(target);
+            return;
+        }
+    }
+    ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+}
+
+pub fn parse_grammatical_features<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter],
+    nodes: &[::Node<'a>],
+    output: &mut Option<::FormOf<'a>>,
+) -> usize {
+    if output.is_some() {
+        ::add_warning(context, template_node, ::WarningMessage::Duplicate);
+        return 0;
+    }
+    let mut form_of = ::FormOf::default();
+    if !parameters.is_empty() {
+        *output = Some(form_of);
+        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+        return 0;
+    }
+    // The items are usually written as an unordered list, but sometimes as a definition list like other sections.
+    match nodes.first() {
+        Some(::Node::DefinitionList { items, .. }) => {
+            for item in items {
+                if item.type_ == ::Details {
+                    parse_item(context, item, &item.nodes, &mut form_of);
+                } else {
+                    ::add_warning(context, item, ::WarningMessage::Unrecognized);
+                }
+            }
+        }
+        Some(::Node::UnorderedList { items, .. }) => {
+            for item in items {
+                parse_item(context, item, &item.nodes, &mut form_of);
+            }
+        }
+        _ => {
+            *output = Some(form_of);
+            ::add_warning(context, template_node, ::WarningMessage::SectionEmpty);
+            return 0;
+        }
+    }
+    *output = Some(form_of);
+    1
+}
+
+fn parse_item<'a>(
+    context: &mut ::Context<'a>,
+    item: &impl ::parse_wiki_text::Positioned,
+    nodes: &[::Node<'a>],
+    form_of: &mut ::FormOf<'a>,
+) {
+    // The lemma is written in bold, which is irrelevant here.
+    let mut nodes: Vec<_> = nodes
+        .iter()
+        .filter(|node| !matches!(node, ::Node::Bold { .. }))
+        .collect();
+    while let Some(::Node::Text { value, .. }) = nodes.last() {
+        if !value.trim_end_matches('.').trim().is_empty() {
+            break;
+        }
+        nodes.pop();
+    }
+    let (features, pos, lemma) = match nodes.as_slice() {
+        [::Node::Text { value, .. }, ::Node::Link { target, .. }] => match parse_features(value) {
+            None => {
+                ::add_warning(context, item, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some((features, pos)) => (features, pos, target),
+        },
+        _ => {
+            ::add_warning(context, item, ::WarningMessage::ValueUnrecognized);
+            return;
+        }
+    };
+    if form_of.lemma.is_some()
+        && form_of.lemma.as_ref().map(|previous| previous as &str) != Some(lemma)
+        || form_of.pos.is_some() && pos.is_some() && form_of.pos != pos
+    {
+        ::add_warning(context, item, ::WarningMessage::ValueConflicting);
+        return;
+    }
+    form_of.features.push(features);
+    form_of.lemma = Some(::Cow::Borrowed(lemma));
+    form_of.pos = form_of.pos.or(pos);
+}
+
+// Parses a description such as `Nominativ Plural des Substantivs` or `2. Person Singular Indikativ Präsens Aktiv des Verbs`. Each feature may only be given once.
+fn parse_features(text: &str) -> Option<(::Features, Option<::Pos>)> {
+    fn set<T>(field: &mut Option<T>, value: T) -> Option<()> {
+        if field.is_some() {
+            return None;
+        }
+        *field = Some(value);
+        Some(())
+    }
+    let mut features = ::Features::default();
+    let mut pos = None;
+    let mut words = text.split_whitespace();
+    while let Some(word) = words.next() {
+        match word {
+            "1." | "2." | "3." => {
+                if words.next() != Some("Person") {
+                    return None;
+                }
+                set(
+                    &mut features.person,
+                    match word {
+                        "1." => ::Person::First,
+                        "2." => ::Person::Second,
+                        _ => ::Person::Third,
+                    },
+                )
+            }
+            "Akkusativ" => set(&mut features.case, ::Case::Accusative),
+            "Dativ" => set(&mut features.case, ::Case::Dative),
+            "Genitiv" => set(&mut features.case, ::Case::Genitive),
+            "Nominativ" => set(&mut features.case, ::Case::Nominative),
+            "Plural" => set(&mut features.number, ::Number::Plural),
+            "Singular" => set(&mut features.number, ::Number::Singular),
+            "Femininum" => set(&mut features.gender, ::Gender::Feminine),
+            "Maskulinum" => set(&mut features.gender, ::Gender::Masculine),
+            "Neutrum" => set(&mut features.gender, ::Gender::Neuter),
+            "Imperativ" => set(&mut features.mood, ::Mood::Imperative),
+            "Indikativ" => set(&mut features.mood, ::Mood::Indicative),
+            "Konjunktiv" => set(
+                &mut features.mood,
+                match words.next()? {
+                    "I" => ::Mood::SubjunctiveI,
+                    "II" => ::Mood::SubjunctiveIi,
+                    _ => return None,
+                },
+            ),
+            "Futur" => set(
+                &mut features.tense,
+                match words.next()? {
+                    "I" => ::Tense::Future,
+                    "II" => ::Tense::FuturePerfect,
+                    _ => return None,
+                },
+            ),
+            "Perfekt" => set(&mut features.tense, ::Tense::Perfect),
+            "Plusquamperfekt" => set(&mut features.tense, ::Tense::Pluperfect),
+            "Präsens" => set(&mut features.tense, ::Tense::Present),
+            "Präteritum" => set(&mut features.tense, ::Tense::Preterite),
+            "Aktiv" => set(&mut features.voice, ::Voice::Active),
+            "Passiv" | "Vorgangspassiv" => set(&mut features.voice, ::Voice::ProcessPassive),
+            "Zustandspassiv" => set(&mut features.voice, ::Voice::StatePassive),
+            "Infinitiv" => set(&mut features.non_finite, ::NonFinite::Infinitive),
+            "Partizip" => set(
+                &mut features.non_finite,
+                match words.next()? {
+                    "I" => ::NonFinite::PresentParticiple,
+                    "II" => ::NonFinite::PastParticiple,
+                    _ => return None,
+                },
+            ),
+            "gemischten" => set(&mut features.declension, ::Declension::Mixed),
+            "schwachen" => set(&mut features.declension, ::Declension::Weak),
+            "starken" => set(&mut features.declension, ::Declension::Strong),
+            "Komparativs" => set(&mut features.degree, ::Degree::Comparative),
+            "Positivs" => set(&mut features.degree, ::Degree::Positive),
+            "Superlativs" => set(&mut features.degree, ::Degree::Superlative),
+            "Adjektivs" => set(&mut pos, ::Pos::Adjective),
+            "Adverbs" => set(&mut pos, ::Pos::Adverb),
+            "Eigennamens" => set(&mut pos, ::Pos::ProperNoun),
+            "Nachnamens" => set(&mut pos, ::Pos::LastName),
+            "Numerales" => set(&mut pos, ::Pos::Numeral),
+            "Substantivs" => set(&mut pos, ::Pos::Noun),
+            "Toponyms" => set(&mut pos, ::Pos::Toponym),
+            "Verbs" => set(&mut pos, ::Pos::Verb),
+            "Vornamens" => set(&mut pos, ::Pos::FirstName),
+            "Deklination" | "Flexion" | "der" | "des" => Some(()),
+            _ => None,
+        }?;
+    }
+    if features == ::Features::default() {
+        return None;
+    }
+    Some((features, pos))
+}
@@ -0,0 +1,142 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_hyphenation<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter],
+    nodes: &[::Node<'a>],
+    output: &mut Option<Vec<Vec<::Hyphenation<'a>>>>,
+) -> usize {
+    ::parse_list_generic(
+        context,
+        template_node,
+        parameters,
+        nodes,
+        output,
+        |context, items| {
+            let mut hyphenations = vec![];
+            for item in items {
+                if item.type_ != ::Details {
+                    ::add_warning(context, item, ::WarningMessage::Unrecognized);
+                    continue;
+                }
+                if item.nodes.is_empty() {
+                    ::add_warning(context, item, ::WarningMessage::Empty);
+                    continue;
+                }
+                let flowing = ::list::parse_list_item(context, &item.nodes, false);
+                if let Some(line) = parse_line(context, item, flowing) {
+                    hyphenations.push(line);
+                }
+            }
+            hyphenations
+        },
+    )
+}
+
+// A line consists of the hyphenation of the lemma followed by the hyphenation of other forms, each following a template indicating the form, such as `Aus·stel·lung, {{Pl.}} Aus·stel·lun·gen`. Alternatives are separated by commas or semicolons. The lemma is left out for words only used in the plural, as in `{{kSg.}}, {{Pl.}} Leu·te`. Text in italics, such as `''selten:''`, qualifies the alternatives following it and is skipped. `kein Plural` is written in place of the template `kPl.`, and a dash in place of the hyphenation of a form that doesn't exist. Anything else causes a warning, and the line is left out.
+fn parse_line<'a>(
+    context: &mut ::Context<'a>,
+    list_item: &::DefinitionListItem<'a>,
+    flowing: Vec<::Flowing<'a>>,
+) -> Option<Vec<::Hyphenation<'a>>> {
+    let mut hyphenations = vec![];
+    let mut current = ::Hyphenation {
+        alternatives: vec![],
+        form: None,
+        missing: false,
+    };
+    let mut empty = false;
+    let mut italic = false;
+    let mut unrecognized = false;
+    for item in flowing {
+        let (form, missing) = match item {
+            ::Flowing::Comparative => (::WordForm::Comparative, false),
+            ::Flowing::Genitive => (::WordForm::Genitive, false),
+            ::Flowing::Italic => {
+                italic = !italic;
+                continue;
+            }
+            ::Flowing::NoPlural => (::WordForm::Plural, true),
+            ::Flowing::NoSingular => (::WordForm::Singular, true),
+            ::Flowing::PastParticiple => (::WordForm::PastParticiple, false),
+            ::Flowing::Plural => (::WordForm::Plural, false),
+            ::Flowing::Plural1 => (::WordForm::Plural1, false),
+            ::Flowing::Plural2 => (::WordForm::Plural2, false),
+            ::Flowing::Plural3 => (::WordForm::Plural3, false),
+            ::Flowing::Plural4 => (::WordForm::Plural4, false),
+            ::Flowing::Preterite => (::WordForm::Preterite, false),
+            ::Flowing::Superlative => (::WordForm::Superlative, false),
+            ::Flowing::Text { value } => {
+                if italic {
+                    continue;
+                }
+                for alternative in split(&value, &[',', ';']) {
+                    let alternative = ::trim_cow(&alternative);
+                    if alternative.is_empty() {
+                        continue;
+                    }
+                    match &alternative as &str {
+                        "-" | "–" | "—" => current.missing = true,
+                        "kein Plural" => {
+                            empty |= !finish(&mut hyphenations, current);
+                            current = ::Hyphenation {
+                                alternatives: vec![],
+                                form: Some(::WordForm::Plural),
+                                missing: true,
+                            };
+                        }
+                        _ => current
+                            .alternatives
+                            .push(split(&alternative, &['·']).iter().map(::trim_cow).collect()),
+                    }
+                }
+                continue;
+            }
+            _ => {
+                unrecognized = true;
+                continue;
+            }
+        };
+        empty |= !finish(&mut hyphenations, current);
+        current = ::Hyphenation {
+            alternatives: vec![],
+            form: Some(form),
+            missing,
+        };
+    }
+    empty |= !finish(&mut hyphenations, current);
+    if unrecognized {
+        ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+        return None;
+    }
+    if empty {
+        ::add_warning(context, list_item, ::WarningMessage::Empty);
+    }
+    Some(hyphenations)
+}
+
+// Adds the hyphenation unless nothing is given for it. Returns false if nothing is given for a form other than the lemma.
+fn finish<'a>(hyphenations: &mut Vec<::Hyphenation<'a>>, hyphenation: ::Hyphenation<'a>) -> bool {
+    if hyphenation.missing || !hyphenation.alternatives.is_empty() {
+        hyphenations.push(hyphenation);
+        true
+    } else {
+        hyphenation.form.is_none()
+    }
+}
+
+fn split<'a>(value: &::Cow<'a, str>, separators: &[char]) -> Vec<::Cow<'a, str>> {
+    let mut parts = vec![];
+    let mut start = 0;
+    for (index, character) in value.char_indices() {
+        if separators.contains(&character) {
+            parts.push(::slice_cow(value, start, index));
+            start = index + character.len_utf8();
+        }
+    }
+    parts.push(::slice_cow(value, start, value.len()));
+    parts
+}
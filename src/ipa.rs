@@ -0,0 +1,82 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+/// Splits a pronunciation written in IPA into tokens.
+///
+/// Each letter forms a segment together with any diacritics and modifier letters following it, and letters joined by a tie bar, as in `t͡s`, form a single segment. Characters that are not valid in IPA, such as `:` and `'` written instead of `ː` and `ˈ`, result in [`IpaToken::Invalid`](enum.IpaToken.html#variant.Invalid).
+#[must_use]
+pub fn tokenize_ipa(ipa: &str) -> Vec<::IpaToken<'_>> {
+    let mut tokens = vec![];
+    let mut segment_start = None;
+    let mut tied = false;
+    for (index, character) in ipa.char_indices() {
+        if is_letter(character) {
+            if tied {
+                tied = false;
+                continue;
+            }
+            if let Some(start) = segment_start {
+                tokens.push(::IpaToken::Segment(&ipa[start..index]));
+            }
+            segment_start = Some(index);
+            continue;
+        }
+        if is_diacritic(character) {
+            if segment_start.is_none() {
+                tokens.push(::IpaToken::Invalid(character));
+            }
+            tied = character == '\u{361}' || character == '\u{35c}';
+            continue;
+        }
+        if let Some(start) = segment_start.take() {
+            tokens.push(::IpaToken::Segment(&ipa[start..index]));
+        }
+        tied = false;
+        tokens.push(match character {
+            ' ' => ::IpaToken::WordBoundary,
+            '(' => ::IpaToken::OptionalStart,
+            ')' => ::IpaToken::OptionalEnd,
+            '-' => ::IpaToken::Hyphen,
+            '.' => ::IpaToken::SyllableBoundary,
+            'ˈ' => ::IpaToken::PrimaryStress,
+            'ˌ' => ::IpaToken::SecondaryStress,
+            'ː' => ::IpaToken::Long,
+            'ˑ' => ::IpaToken::HalfLong,
+            '‿' => ::IpaToken::Linking,
+            '|' => ::IpaToken::MinorBreak,
+            '‖' => ::IpaToken::MajorBreak,
+            _ => ::IpaToken::Invalid(character),
+        });
+    }
+    if let Some(start) = segment_start {
+        tokens.push(::IpaToken::Segment(&ipa[start..]));
+    }
+    tokens
+}
+
+// Combining diacritics, including tie bars, and modifier letters such as `ʰ` that are written after the letter they modify, except the stress and length marks.
+fn is_diacritic(character: char) -> bool {
+    !matches!(character, 'ˈ' | 'ˌ' | 'ː' | 'ˑ')
+        && matches!(character, '\u{300}'..='\u{36f}' | '\u{2b0}'..='\u{2ff}' | 'ᵊ' | 'ⁿ')
+}
+
+// Besides the letters used in IPA, lowercase Latin letters with precomposed diacritics, such as `ã` and `õ`, are accepted as the letter followed by a combining diacritic would be.
+fn is_letter(character: char) -> bool {
+    ('\u{e0}'..='\u{24f}').contains(&character) && character.is_lowercase()
+        || matches!(
+            character,
+            'a'..='z'
+                | '\u{250}'..='\u{2af}'
+                | 'ǀ'
+                | 'ǁ'
+                | 'ǂ'
+                | 'ǃ'
+                | 'β'
+                | 'θ'
+                | 'χ'
+                | 'ᵻ'
+                | 'ᵿ'
+                | 'ⱱ'
+        )
+}
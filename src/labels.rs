@@ -0,0 +1,211 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+macro_rules! labels {
+    ( $( $variant:ident: $kind:ident, $name:tt $( ( $abbreviation:tt ) )* $( , $alias:tt )*; )+ ) => {
+        /// Context label, such as a label for register, region or domain.
+        #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub enum Label {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+        }
+
+        impl Label {
+            /// All labels, ordered by variant.
+            pub const ALL: &'static [Label] = &[$( Label::$variant ),+];
+
+            /// Returns the abbreviation of the label if it has one.
+            ///
+            /// Labels with an abbreviation can also be given as a template with the abbreviation as its name, such as [`ugs.`](https://de.wiktionary.org/wiki/Vorlage:ugs.).
+            #[must_use]
+            pub fn abbreviation(self) -> Option<&'static str> {
+                match self {
+                    $( Label::$variant => {
+                        let abbreviations: &[&'static str] = &[$( $abbreviation ),*];
+                        abbreviations.first().cloned()
+                    } )+
+                }
+            }
+
+            /// Returns the label corresponding to the given abbreviation if any.
+            #[must_use]
+            pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+                Some(match abbreviation {
+                    $( $( $abbreviation => Label::$variant, )* )+
+                    _ => return None,
+                })
+            }
+
+            /// Returns the label corresponding to the given name or abbreviation if any.
+            ///
+            /// Besides the name returned by [`name`](#method.name) and the abbreviation returned by [`abbreviation`](#method.abbreviation), some labels are also recognized by alternative names.
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Self> {
+                Some(match name {
+                    $(
+                        $name => Label::$variant,
+                        $( $abbreviation => Label::$variant, )*
+                        $( $alias => Label::$variant, )*
+                    )+
+                    _ => return None,
+                })
+            }
+
+            /// Returns the kind of the label.
+            #[must_use]
+            pub fn kind(self) -> LabelKind {
+                match self {
+                    $( Label::$variant => LabelKind::$kind ),+
+                }
+            }
+
+            /// Returns the label as written out in German.
+            #[must_use]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $( Label::$variant => $name ),+
+                }
+            }
+        }
+    };
+}
+
+/// Kind of context label.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelKind {
+    /// Subject area
+    Domain,
+
+    /// Grammatical use, such as transitive or reflexive
+    Grammar,
+
+    /// Region where the word is used
+    Region,
+
+    /// Register or connotation, such as colloquial or pejorative
+    Register,
+
+    /// Time or frequency of use, such as dated or rare
+    Usage,
+}
+
+/// Reference to a context label.
+///
+/// Both a known and an unknown label are serialized as the label written out in German, such as `umgangssprachlich`, and resolved with [`from_name`](#method.from_name) when deserialized.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LabelReference<'a> {
+    /// A label known to the parser.
+    Known(Label),
+
+    /// A label not known to the parser, as written.
+    Unknown(::Cow<'a, str>),
+}
+
+impl<'a> LabelReference<'a> {
+    /// Resolves a label given by name or abbreviation, keeping the text if it's not known.
+    #[must_use]
+    pub fn from_name(name: ::Cow<'a, str>) -> Self {
+        match Label::from_name(&name) {
+            None => LabelReference::Unknown(name),
+            Some(label) => LabelReference::Known(label),
+        }
+    }
+
+    /// Returns the label as written out in German for a known label, or the label as written for an unknown label.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            LabelReference::Known(label) => label.name(),
+            LabelReference::Unknown(name) => name,
+        }
+    }
+}
+
+impl<'a> ::serde::Serialize for LabelReference<'a> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'a, 'de> ::serde::Deserialize<'de> for LabelReference<'a> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|name| LabelReference::from_name(::Cow::Owned(name)))
+    }
+}
+
+// Each line contains the variant, the kind, the name as written out, the abbreviation if any in parentheses and any alternative names.
+labels! {
+    Agriculture: Domain, "Landwirtschaft";
+    American: Region, "amerikanisch" ("amer.");
+    Anatomy: Domain, "Anatomie" ("anat.");
+    Architecture: Domain, "Architektur";
+    Astronomy: Domain, "Astronomie";
+    Austrian: Region, "österreichisch" ("österr."), "Österreich";
+    Aviation: Domain, "Luftfahrt";
+    Biology: Domain, "Biologie" ("biol.");
+    Botany: Domain, "Botanik" ("bot.");
+    British: Region, "britisch" ("brit.");
+    Casual: Register, "salopp";
+    Chemistry: Domain, "Chemie" ("chem.");
+    Childish: Register, "kindersprachlich" ("kinderspr."), "Kindersprache";
+    Colloquial: Register, "umgangssprachlich" ("ugs."), "Umgangssprache";
+    Computing: Domain, "Informatik", "EDV";
+    Dated: Usage, "veraltend" ("vatd.");
+    Derogatory: Register, "abwertend" ("abw."), "pejorativ";
+    Dialectal: Region, "mundartlich" ("mundartl."), "Dialekt";
+    EastGerman: Region, "DDR";
+    Economics: Domain, "Wirtschaft" ("wirtsch.");
+    Elevated: Register, "gehoben" ("geh.");
+    Euphemistic: Register, "verhüllend" ("verhüll."), "euphemistisch";
+    Everyday: Register, "alltagssprachlich" ("alltagsspr.");
+    Familiar: Register, "familiär" ("fam.");
+    Figurative: Register, "übertragen" ("übertr."), "figurativ", "bildlich";
+    Formal: Register, "förmlich";
+    Gastronomy: Domain, "Gastronomie";
+    Geography: Domain, "Geografie", "Geographie";
+    Geology: Domain, "Geologie";
+    GermanyGerman: Region, "bundesdeutsch" ("bundesdt."), "Deutschland";
+    Historical: Usage, "historisch" ("hist.");
+    Humorous: Register, "scherzhaft" ("scherzh.");
+    Hunting: Domain, "Jägersprache" ("jägerspr.");
+    Impersonal: Grammar, "unpersönlich" ("unpers.");
+    Intransitive: Grammar, "intransitiv" ("intrans.");
+    Ironic: Register, "ironisch" ("iron.");
+    Jargon: Register, "Jargon";
+    Law: Domain, "Recht", "Rechtswesen", "juristisch";
+    Learned: Register, "bildungssprachlich" ("bildungsspr.");
+    Linguistics: Domain, "Linguistik", "Sprachwissenschaft";
+    Literary: Register, "literarisch";
+    Mathematics: Domain, "Mathematik" ("math.");
+    Medicine: Domain, "Medizin" ("med.");
+    Military: Domain, "Militär" ("mil.");
+    Music: Domain, "Musik";
+    Nautical: Domain, "Seemannssprache" ("seemannsspr."), "Seefahrt";
+    Neologism: Usage, "Neologismus";
+    NorthGerman: Region, "norddeutsch" ("nordd."), "norddt.";
+    Obsolete: Usage, "veraltet" ("va.");
+    Officialese: Register, "amtssprachlich" ("amtsspr."), "Amtssprache";
+    Philosophy: Domain, "Philosophie";
+    Physics: Domain, "Physik" ("phys.");
+    Poetic: Register, "poetisch" ("poet."), "dichterisch";
+    Politics: Domain, "Politik";
+    Psychology: Domain, "Psychologie";
+    Rare: Usage, "selten";
+    Reflexive: Grammar, "reflexiv" ("refl.");
+    Regional: Region, "regional" ("reg."), "landschaftlich", "landsch.";
+    Religion: Domain, "Religion";
+    Scientific: Register, "fachsprachlich" ("fachspr."), "Fachsprache";
+    SouthGerman: Region, "süddeutsch" ("süddt."), "südd.";
+    Sports: Domain, "Sport";
+    Swiss: Region, "schweizerisch" ("schweiz."), "Schweiz";
+    Technology: Domain, "Technik";
+    Transitive: Grammar, "transitiv" ("trans.");
+    Vulgar: Register, "vulgär" ("vulg."), "derb";
+    Youth: Register, "jugendsprachlich" ("jugendspr."), "Jugendsprache";
+    Zoology: Domain, "Zoologie" ("zool.");
+}
@@ -26,7 +26,7 @@ pub fn parse_language<'a>(
                 }
                 if *level == 3 {
                     node_index += 1;
-                    match heading_child_nodes.get(0) {
+                    match heading_child_nodes.first() {
                         None => {
                             ::add_warning(context, node, ::WarningMessage::Empty);
                             continue;
@@ -41,7 +41,7 @@ pub fn parse_language<'a>(
                                     template_node,
                                     parameters,
                                 ) {
-                                    let mut details =
+                                    let details =
                                         parse_details(context, &heading_child_nodes[1..]);
                                     node_index += ::pos_section::parse_pos_section(
                                         context,
@@ -59,11 +59,11 @@ pub fn parse_language<'a>(
                     continue;
                 }
             }
-            ::Node::Template { name, .. } => if ::text_equals(name, "erweitern") {
+            ::Node::Template { name, .. } if ::text_equals(name, "erweitern") => {
                 node_index += 1;
                 ::add_warning(context, node, ::WarningMessage::Supplementary);
                 continue;
-            },
+            }
             _ => {}
         }
         node_index += 1;
@@ -186,9 +186,9 @@ fn parse_details<'a>(context: &mut ::Context<'a>, nodes: &[::Node<'a>]) -> Vec<:
             },
             ::Node::Text { mut value, .. } => {
                 if details.is_empty() {
-                    value = value.trim_left();
+                    value = value.trim_start();
                     if value.starts_with(',') {
-                        value = value[1..].trim_left();
+                        value = value[1..].trim_start();
                     }
                     if value.is_empty() {
                         continue;
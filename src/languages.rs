@@ -2,386 +2,665 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
-/// Identifier for a language.
-#[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(rename_all = "snake_case")]
-pub enum Language {
-    Aa,
-    Ab,
-    Ae,
-    Af,
-    Ak,
-    Am,
-    An,
-    Ar,
-    Arc,
-    As,
-    Av,
-    Ay,
-    Az,
-    Ba,
-    Be,
-    Bg,
-    Bh,
-    Bi,
-    Bm,
-    Bn,
-    Bo,
-    Br,
-    Bs,
-    By,
-    Ca,
-    Ce,
-    Ch,
-    Co,
-    Cr,
-    Cs,
-    Cu,
-    Cv,
-    Cy,
-    Da,
-    De,
-    Dv,
-    Dz,
-    Ee,
-    El,
-    En,
-    Eo,
-    Es,
-    Et,
-    Eu,
-    Fa,
-    Ff,
-    Fi,
-    Fj,
-    Fo,
-    Fr,
-    Fy,
-    Ga,
-    Gd,
-    Gl,
-    Gn,
-    Gu,
-    Gv,
-    Ha,
-    He,
-    Hi,
-    Ho,
-    Hr,
-    Ht,
-    Hu,
-    Hy,
-    Hz,
-    Ia,
-    Id,
-    Ie,
-    Ii,
-    Ik,
-    Is,
-    It,
-    Iu,
-    Ja,
-    Jv,
-    Ka,
-    Kg,
-    Ki,
-    Kj,
-    Kk,
-    Kl,
-    Km,
-    Kn,
-    Ko,
-    Kr,
-    Ks,
-    Ku,
-    Kv,
-    Kw,
-    Ky,
-    La,
-    Lb,
-    Lg,
-    Li,
-    Ln,
-    Lo,
-    Lt,
-    Lu,
-    Lv,
-    Mg,
-    Mh,
-    Mi,
-    Mk,
-    Ml,
-    Mn,
-    Mr,
-    Ms,
-    Mt,
-    My,
-    Na,
-    Nb,
-    Nd,
-    Ne,
-    Ng,
-    Nl,
-    Nn,
-    No,
-    Nr,
-    Nv,
-    Ny,
-    Oc,
-    Oj,
-    Om,
-    Or,
-    Os,
-    Pa,
-    Pi,
-    Pl,
-    Ps,
-    Pt,
-    Qu,
-    Rm,
-    Rn,
-    Ro,
-    Ru,
-    Rw,
-    Sa,
-    Sc,
-    Sd,
-    Se,
-    Sg,
-    Si,
-    Sk,
-    Sl,
-    Sm,
-    Sn,
-    So,
-    Sq,
-    Sr,
-    Ss,
-    St,
-    Su,
-    Sv,
-    Sw,
-    Ta,
-    Te,
-    Tg,
-    Th,
-    Ti,
-    Tk,
-    Tl,
-    Tn,
-    To,
-    Tr,
-    Ts,
-    Tt,
-    Tw,
-    Ty,
-    Ug,
-    Uk,
-    Ur,
-    Uz,
-    Ve,
-    Vi,
-    Vo,
-    Wa,
-    Wo,
-    Xh,
-    Yi,
-    Yo,
-    Za,
-    Zh,
-    Zu,
+macro_rules! languages {
+    ( $( $variant:ident: $code:tt $( | $code_alias:tt )*, $iso639_3:expr, $name:tt $( , $alias:tt )*; )+ ) => {
+        /// Identifier for a language.
+        ///
+        /// Each variant is named after the code Wiktionary uses for the language, which is the ISO 639-1 code where one exists and otherwise usually the ISO 639-3 code.
+        #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub enum Language {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+        }
+
+        impl Language {
+            /// All languages, ordered by code.
+            pub const ALL: &'static [Language] = &[$( Language::$variant ),+];
+
+            /// Returns the code Wiktionary uses for the language, as used in templates such as [`Ü`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C) and the language templates in translation tables.
+            #[must_use]
+            pub fn code(self) -> &'static str {
+                match self {
+                    $( Language::$variant => $code ),+
+                }
+            }
+
+            /// Returns the language corresponding to the given code Wiktionary uses for the language if any.
+            ///
+            /// Besides the code returned by [`code`](#method.code), the ISO 639-3 code returned by [`iso639_3`](#method.iso639_3) and some variants of the code also used by Wiktionary are recognized.
+            #[must_use]
+            pub fn from_code(code: &str) -> Option<Self> {
+                Some(match code {
+                    $( $code $( | $code_alias )* => Language::$variant, )+
+                    _ => {
+                        return Language::ALL
+                            .iter()
+                            .cloned()
+                            .find(|language| language.iso639_3() == Some(code))
+                    }
+                })
+            }
+
+            /// Returns the language corresponding to the given language name if any.
+            ///
+            /// Besides the name returned by [`name`](#method.name), some languages are also recognized by alternative names.
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Self> {
+                Some(match name {
+                    $(
+                        $name => Language::$variant,
+                        $( $alias => Language::$variant, )*
+                    )+
+                    _ => return None,
+                })
+            }
+
+            /// Returns the ISO 639-3 code of the language if it has one.
+            ///
+            /// For macrolanguages this is the code of the macrolanguage. Language families, other groups of languages and written standards of a language, such as simplified Chinese, have no ISO 639-3 code.
+            #[must_use]
+            pub fn iso639_3(self) -> Option<&'static str> {
+                match self {
+                    $( Language::$variant => $iso639_3 ),+
+                }
+            }
+
+            /// Returns the German name of the language, as used in the template [`Sprache`](https://de.wiktionary.org/wiki/Vorlage:Sprache).
+            #[must_use]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $( Language::$variant => $name ),+
+                }
+            }
+        }
+    };
 }
 
-impl Language {
-    /// Returns the language corresponding to the given language name if any.
-    pub fn from_name(name: &str) -> Option<Self> {
-        Some(match name {
-            "(Neu-)Awarisch" => Language::Av,
-            "(Neu-)Griechisch" => Language::El,
-            "(Schottisch-)Gälisch" => Language::Gd,
-            "Abchasisch" => Language::Ab,
-            "Afar" => Language::Aa,
-            "Afrikaans" => Language::Af,
-            "Akan" => Language::Ak,
-            "Albanisch" => Language::Sq,
-            "Altkirchenslawisch" => Language::Cu,
-            "Amharisch" => Language::Am,
-            "Anishinabe" => Language::Oj,
-            "Arabisch" => Language::Ar,
-            "Aragonesisch" => Language::An,
-            "Aramäisch" => Language::Arc,
-            "Armenisch" => Language::Hy,
-            "Aserbaidschanisch" => Language::Az,
-            "Assamesisch/Assami" => Language::As,
-            "Avestisch" => Language::Ae,
-            "Aymara" => Language::Ay,
-            "Bambara" => Language::Bm,
-            "Banyumasan" => Language::By,
-            "Baschkirisch" => Language::Ba,
-            "Baskisch" => Language::Eu,
-            "Bengalisch" => Language::Bn,
-            "Bihari" => Language::Bh,
-            "Birmanisch" => Language::My,
-            "Bislama" => Language::Bi,
-            "Bokmål" => Language::Nb,
-            "Bosnisch" => Language::Bs,
-            "Bretonisch" => Language::Br,
-            "Bulgarisch" => Language::Bg,
-            "Chamorro" => Language::Ch,
-            "Chichewa" => Language::Ny,
-            "Chinesisch" => Language::Zh,
-            "Cree" => Language::Cr,
-            "Deutsch" => Language::De,
-            "Dhivehi" => Language::Dv,
-            "Dzongkha" => Language::Dz,
-            "Dänisch" => Language::Da,
-            "Englisch" => Language::En,
-            "Esperanto" => Language::Eo,
-            "Estnisch" => Language::Et,
-            "Ewe" => Language::Ee,
-            "Fidschi" => Language::Fj,
-            "Finnisch" => Language::Fi,
-            "Französisch" => Language::Fr,
-            "Friesisch" => Language::Fy,
-            "Fula" => Language::Ff,
-            "Färöisch" => Language::Fo,
-            "Galicisch" => Language::Gl,
-            "Ganda" => Language::Lg,
-            "Georgisch" => Language::Ka,
-            "Guaraní" => Language::Gn,
-            "Gujarati" => Language::Gu,
-            "Haitianisch" => Language::Ht,
-            "Hausa" => Language::Ha,
-            "Hebräisch" => Language::He,
-            "Herero" => Language::Hz,
-            "Hindi" => Language::Hi,
-            "Hiri Motu" => Language::Ho,
-            "Indonesisch" => Language::Id,
-            "Interlingua" => Language::Ia,
-            "Interlingue" => Language::Ie,
-            "Inuktitut" => Language::Iu,
-            "Inupiaq" => Language::Ik,
-            "Irisch" => Language::Ga,
-            "Isländisch" => Language::Is,
-            "Italienisch" => Language::It,
-            "Japanisch" => Language::Ja,
-            "Javanisch" => Language::Jv,
-            "Jiddisch" => Language::Yi,
-            "Kalaallisut; Grönländisch" => Language::Kl,
-            "Kannada" => Language::Kn,
-            "Kanuri" => Language::Kr,
-            "Kasachisch" => Language::Kk,
-            "Kaschmirisch" => Language::Ks,
-            "Katalanisch" => Language::Ca,
-            "Khmer" => Language::Km,
-            "Kikuyu" => Language::Ki,
-            "Kiluba (Luba-Katanga)" => Language::Lu,
-            "Kinyarwanda" => Language::Rw,
-            "Kirgisisch" => Language::Ky,
-            "Kirundi" => Language::Rn,
-            "Komi" => Language::Kv,
-            "Kongo, Kikongo" => Language::Kg,
-            "Koreanisch" => Language::Ko,
-            "Kornisch" => Language::Kw,
-            "Korsisch" => Language::Co,
-            "Kroatisch" => Language::Hr,
-            "Kuanyama" => Language::Kj,
-            "Kurdisch" => Language::Ku,
-            "Laotisch" => Language::Lo,
-            "Lateinisch" => Language::La,
-            "Lettisch" => Language::Lv,
-            "Limburgisch" => Language::Li,
-            "Lingala" => Language::Ln,
-            "Litauisch" => Language::Lt,
-            "Luxemburgisch" => Language::Lb,
-            "Madagassisch" => Language::Mg,
-            "Malaiisch" => Language::Ms,
-            "Malayalam" => Language::Ml,
-            "Maltesisch" => Language::Mt,
-            "Manx" => Language::Gv,
-            "Maori" => Language::Mi,
-            "Marathi" => Language::Mr,
-            "Marshallesisch" => Language::Mh,
-            "Mazedonisch" => Language::Mk,
-            "Mongolisch" => Language::Mn,
-            "Nauruisch" => Language::Na,
-            "Navajo" => Language::Nv,
-            "Ndonga" => Language::Ng,
-            "Nepalesisch" => Language::Ne,
-            "Niederländisch" => Language::Nl,
-            "Nord-Ndebele" => Language::Nd,
-            "Norwegisch" => Language::No,
-            "Nynorsk (Neunorwegisch)" => Language::Nn,
-            "Okzitanisch" => Language::Oc,
-            "Oriya" => Language::Or,
-            "Oromo" => Language::Om,
-            "Ossetisch" => Language::Os,
-            "Pali" => Language::Pi,
-            "Pandschabi" => Language::Pa,
-            "Paschtu" => Language::Ps,
-            "Persisch" => Language::Fa,
-            "Polnisch" => Language::Pl,
-            "Portugiesisch" => Language::Pt,
-            "Quechua" => Language::Qu,
-            "Rumänisch" => Language::Ro,
-            "Russisch" => Language::Ru,
-            "Rätoromanisch" => Language::Rm,
-            "Samisch" => Language::Se,
-            "Samoanisch" => Language::Sm,
-            "Sango" => Language::Sg,
-            "Sanskrit" => Language::Sa,
-            "Sardisch" => Language::Sc,
-            "Schwedisch" => Language::Sv,
-            "Serbisch" => Language::Sr,
-            "Sesotho" => Language::St,
-            "Setswana" => Language::Tn,
-            "Shona" => Language::Sn,
-            "Sindhi" => Language::Sd,
-            "Singhalesisch" => Language::Si,
-            "Siswati" => Language::Ss,
-            "Slowakisch" => Language::Sk,
-            "Slowenisch" => Language::Sl,
-            "Somali" => Language::So,
-            "Spanisch" => Language::Es,
-            "Sundanesisch" => Language::Su,
-            "Swahili" => Language::Sw,
-            "Süd-Ndebele" => Language::Nr,
-            "Tadschikisch" => Language::Tg,
-            "Tagalog" => Language::Tl,
-            "Tahitianisch" => Language::Ty,
-            "Tamilisch" => Language::Ta,
-            "Tatarisch" => Language::Tt,
-            "Telugu" => Language::Te,
-            "Thailändisch" => Language::Th,
-            "Tibetisch" => Language::Bo,
-            "Tigrinya" => Language::Ti,
-            "Tongaisch" => Language::To,
-            "Tschechisch" => Language::Cs,
-            "Tschetschenisch" => Language::Ce,
-            "Tschuwaschisch" => Language::Cv,
-            "Tsonga" => Language::Ts,
-            "Turkmenisch" => Language::Tk,
-            "Twi" => Language::Tw,
-            "Türkisch" => Language::Tr,
-            "Uigurisch" => Language::Ug,
-            "Ukrainisch" => Language::Uk,
-            "Ungarisch" => Language::Hu,
-            "Urdu" => Language::Ur,
-            "Usbekisch" => Language::Uz,
-            "Venda" => Language::Ve,
-            "Vietnamesisch" => Language::Vi,
-            "Volapük" => Language::Vo,
-            "Walisisch" => Language::Cy,
-            "Wallonisch" => Language::Wa,
-            "Weißrussisch" => Language::Be,
-            "Wolof" => Language::Wo,
-            "Yi" => Language::Ii,
-            "Yoruba" => Language::Yo,
-            "Zhuang" => Language::Za,
-            "isiXhosa" => Language::Xh,
-            "isiZulu" => Language::Zu,
-            _ => return None,
-        })
+/// Reference to a language by a code used by Wiktionary.
+///
+/// Serialized as the code returned by [`code`](#method.code) and resolved with [`from_code`](#method.from_code) when deserialized. For a known language given by a variant of its code, such as `gr` or `mo`, this is the main code of the language, such as `el` or `ro`, rather than the code as written.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LanguageReference<'a> {
+    /// A language known to the parser.
+    Known(Language),
+
+    /// A code not known to the parser, such as a code for a group of languages or a regional variety.
+    Unknown(::Cow<'a, str>),
+}
+
+impl<'a> LanguageReference<'a> {
+    /// Returns the code referring to the language.
+    #[must_use]
+    pub fn code(&self) -> &str {
+        match self {
+            LanguageReference::Known(language) => language.code(),
+            LanguageReference::Unknown(code) => code,
+        }
+    }
+
+    /// Resolves a code used by Wiktionary, keeping the code if it's not known.
+    #[must_use]
+    pub fn from_code(code: ::Cow<'a, str>) -> Self {
+        match Language::from_code(&code) {
+            None => LanguageReference::Unknown(code),
+            Some(language) => LanguageReference::Known(language),
+        }
     }
+
+    /// Returns the language if it's known.
+    #[must_use]
+    pub fn language(&self) -> Option<Language> {
+        match self {
+            LanguageReference::Known(language) => Some(*language),
+            LanguageReference::Unknown(_) => None,
+        }
+    }
+}
+
+impl<'a> ::serde::Serialize for LanguageReference<'a> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'a, 'de> ::serde::Deserialize<'de> for LanguageReference<'a> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)
+            .map(|code| LanguageReference::from_code(::Cow::Owned(code)))
+    }
+}
+
+// Each line contains the variant, the code used by Wiktionary followed by any variants of it, the ISO 639-3 code, the name used in the template `Sprache` and any alternative names.
+languages! {
+    Aa: "aa", Some("aar"), "Afar";
+    Ab: "ab", Some("abk"), "Abchasisch";
+    Abq: "abq", Some("abq"), "Abasinisch";
+    Ace: "ace", Some("ace"), "Acehnesisch";
+    Ady: "ady", Some("ady"), "Adygeisch";
+    Ae: "ae", Some("ave"), "Avestisch";
+    Aeb: "aeb", Some("aeb"), "Tunesisch-Arabisch";
+    Af: "af", Some("afr"), "Afrikaans";
+    Agf: "agf", Some("agf"), "Arguni";
+    Agj: "agj", Some("agj"), "Argobba";
+    Aie: "aie", Some("aie"), "Amara";
+    Ain: "ain", Some("ain"), "Ainu";
+    Ajp: "ajp", Some("ajp"), "Südlevantinisches Arabisch";
+    Ak: "ak", Some("aka"), "Akan";
+    Akg: "akg", Some("akg"), "Anakalangu";
+    Akk: "akk", Some("akk"), "Akkadisch";
+    Akz: "akz", Some("akz"), "Alabama";
+    Ale: "ale", Some("ale"), "Aleutisch";
+    Alp: "alp", Some("alp"), "Alune";
+    Als: "als", None, "Alemannisch", "Elsässisch";
+    Alt: "alt", Some("alt"), "Altaisch";
+    Am: "am", Some("amh"), "Amharisch";
+    Amk: "amk", Some("amk"), "Ambai";
+    Amu: "amu", Some("amu"), "Guerrero-Amuzgo";
+    An: "an", Some("arg"), "Aragonesisch";
+    Ang: "ang", Some("ang"), "Altenglisch";
+    Apc: "apc", Some("apc"), "Nordlevantinisches Arabisch";
+    Apw: "apw", Some("apw"), "Westliches Apache";
+    Ar: "ar", Some("ara"), "Arabisch";
+    Arc: "arc", Some("arc"), "Aramäisch";
+    Arn: "arn", Some("arn"), "Mapudungun";
+    Arq: "arq", Some("arq"), "Algerisch-Arabisch";
+    Arw: "arw", Some("arw"), "Arawak";
+    Ary: "ary", Some("ary"), "Marokkanisch-Arabisch";
+    Arz: "arz", Some("arz"), "Ägyptisch-Arabisch";
+    As: "as", Some("asm"), "Assamesisch/Assami";
+    Ast: "ast", Some("ast"), "Asturisch";
+    Aua: "aua", Some("aua"), "Asumboa";
+    Av: "av", Some("ava"), "(Neu-)Awarisch";
+    Ay: "ay", Some("aym"), "Aymara";
+    Ayl: "ayl", Some("ayl"), "Libysch-Arabisch";
+    Az: "az", Some("aze"), "Aserbaidschanisch";
+    Azb: "azb", Some("azb"), "Südaserbaidschanisch";
+    Azj: "azj", Some("azj"), "Nordaserbaidschanisch";
+    Ba: "ba", Some("bak"), "Baschkirisch";
+    Baa: "baa", Some("baa"), "Babatana";
+    Bal: "bal", Some("bal"), "Belutschisch";
+    Ban: "ban", Some("ban"), "Balinesisch";
+    Bar: "bar", Some("bar"), "Bairisch", "Wienerisch";
+    Bat: "bat", None, "Baltisch";
+    Bbc: "bbc", Some("bbc"), "Toba-Batak";
+    Bci: "bci", Some("bci"), "Baoulé";
+    Bcl: "bcl", Some("bcl"), "Bikolano";
+    Bcm: "bcm", Some("bcm"), "Banoni";
+    Be: "be", Some("bel"), "Weißrussisch";
+    Bem: "bem", Some("bem"), "Bemba";
+    Ber: "ber", None, "Berbersprachen";
+    Bg: "bg", Some("bul"), "Bulgarisch";
+    Bgc: "bgc", Some("bgc"), "Haryanvi";
+    Bh: "bh", None, "Bihari";
+    Bhw: "bhw", Some("bhw"), "Biak";
+    Bi: "bi", Some("bis"), "Bislama";
+    Bjn: "bjn", Some("bjn"), "Banjaresisch";
+    Bla: "bla", Some("bla"), "Blackfoot";
+    Bm: "bm", Some("bam"), "Bambara";
+    Bmg: "bmg", Some("bmg"), "Bamwe";
+    Bn: "bn", Some("ben"), "Bengalisch";
+    Bnd: "bnd", Some("bnd"), "Bandanesisch";
+    Bo: "bo", Some("bod"), "Tibetisch";
+    Bpy: "bpy", Some("bpy"), "Bishnupriya Manipuri";
+    Br: "br", Some("bre"), "Bretonisch";
+    Bs: "bs", Some("bos"), "Bosnisch";
+    Bty: "bty", Some("bty"), "Bobot";
+    Bua: "bua", Some("bua"), "Burjatisch";
+    Bug: "bug", Some("bug"), "Buginesisch";
+    Bxr: "bxr", Some("bxr"), "Russland-Burjatisch";
+    By: "by", None, "Banyumasan";
+    Bzg: "bzg", Some("bzg"), "Babuza";
+    Ca: "ca", Some("cat"), "Katalanisch";
+    Ccc: "ccc", Some("ccc"), "Chamicuro";
+    Cdo: "cdo", Some("cdo"), "Min Dong";
+    Ce: "ce", Some("che"), "Tschetschenisch";
+    Ceb: "ceb", Some("ceb"), "Cebuano";
+    Cel: "cel", None, "Keltisch";
+    Ch: "ch", Some("cha"), "Chamorro";
+    Chc: "chc", Some("chc"), "Catawba";
+    Chm: "chm", Some("chm"), "Mari";
+    Cho: "cho", Some("cho"), "Choctaw";
+    Chp: "chp", Some("chp"), "Chipewyan";
+    Chr: "chr", Some("chr"), "Cherokee";
+    Chy: "chy", Some("chy"), "Cheyenne";
+    Cim: "cim", Some("cim"), "Zimbrisch";
+    Cjs: "cjs", Some("cjs"), "Schorisch";
+    Ckb: "ckb", Some("ckb"), "Zentralkurdisch", "Sorani";
+    Ckt: "ckt", Some("ckt"), "Tschuktschisch";
+    Cnx: "cnx", Some("cnx"), "Mittelkornisch";
+    Co: "co", Some("cos"), "Korsisch";
+    Com: "com", Some("com"), "Comanche";
+    Cop: "cop", Some("cop"), "Koptisch";
+    Cr: "cr", Some("cre"), "Cree";
+    Crh: "crh", Some("crh"), "Krimtatarisch";
+    Cro: "cro", Some("cro"), "Crow";
+    Crs: "crs", Some("crs"), "Seychellenkreol";
+    Cs: "cs", Some("ces"), "Tschechisch";
+    Csb: "csb", Some("csb"), "Kaschubisch";
+    Cu: "cu", Some("chu"), "Altkirchenslawisch";
+    Cv: "cv", Some("chv"), "Tschuwaschisch";
+    Cy: "cy", Some("cym"), "Walisisch";
+    Da: "da", Some("dan"), "Dänisch";
+    Ddn: "ddn", Some("ddn"), "Dendi";
+    De: "de", Some("deu"), "Deutsch";
+    Dhv: "dhv", Some("dhv"), "Drehu";
+    Diq: "diq", Some("diq"), "Süd-Zazaki";
+    Dje: "dje", Some("dje"), "Zarma";
+    Dlm: "dlm", Some("dlm"), "Dalmatisch";
+    Dng: "dng", Some("dng"), "Dunganisch";
+    Dob: "dob", Some("dob"), "Dobu";
+    Dsb: "dsb", Some("dsb"), "Niedersorbisch";
+    Dum: "dum", Some("dum"), "Mittelniederländisch";
+    Dv: "dv", Some("div"), "Dhivehi";
+    Dz: "dz", Some("dzo"), "Dzongkha";
+    Ee: "ee", Some("ewe"), "Ewe";
+    Egy: "egy", Some("egy"), "Ägyptisch";
+    El: "el" | "gr", Some("ell"), "Neugriechisch", "(Neu-)Griechisch";
+    Eml: "eml", Some("eml"), "Emilianisch-Romagnolisch";
+    En: "en", Some("eng"), "Englisch";
+    Enm: "enm", Some("enm"), "Mittelenglisch";
+    Eo: "eo", Some("epo"), "Esperanto";
+    Erk: "erk", Some("erk"), "Süd-Efate";
+    Es: "es", Some("spa"), "Spanisch";
+    Et: "et", Some("est"), "Estnisch";
+    Eu: "eu", Some("eus"), "Baskisch";
+    Ext: "ext", Some("ext"), "Extremadurisch";
+    Fa: "fa", Some("fas"), "Persisch";
+    Ff: "ff", Some("ful"), "Fula";
+    Fi: "fi", Some("fin"), "Finnisch";
+    Fj: "fj", Some("fij"), "Fidschi";
+    Fng: "fng", Some("fng"), "Fanagalo";
+    Fo: "fo", Some("fao"), "Färöisch";
+    Fon: "fon", Some("fon"), "Fon";
+    Fr: "fr", Some("fra"), "Französisch";
+    Frk: "frk", Some("frk"), "Fränkisch";
+    Frm: "frm", Some("frm"), "Mittelfranzösisch";
+    Fro: "fro", Some("fro"), "Altfranzösisch";
+    Frp: "frp", Some("frp"), "Frankoprovenzalisch";
+    Frr: "frr", Some("frr"), "Nordfriesisch";
+    Frs: "frs", Some("frs"), "Ostfriesisch";
+    Fur: "fur", Some("fur"), "Furlanisch", "Friaulisch";
+    Fy: "fy", Some("fry"), "Friesisch", "Westfriesisch";
+    Ga: "ga", Some("gle"), "Irisch";
+    Gag: "gag", Some("gag"), "Gagausisch";
+    Gan: "gan", Some("gan"), "Gan";
+    Gay: "gay", Some("gay"), "Gayo";
+    Gcf: "gcf", Some("gcf"), "Guadeloupe-Kreolisch";
+    Gd: "gd", Some("gla"), "(Schottisch-)Gälisch";
+    Gdq: "gdq", Some("gdq"), "Mehri";
+    Gem: "gem", None, "Germanisch";
+    Gez: "gez", Some("gez"), "Altäthiopisch";
+    Gha: "gha", Some("gha"), "Ghadamès";
+    Gil: "gil", Some("gil"), "Kiribatisch";
+    Gl: "gl", Some("glg"), "Galicisch";
+    Glk: "glk", Some("glk"), "Gilaki";
+    Gmh: "gmh", Some("gmh"), "Mittelhochdeutsch";
+    Gml: "gml", Some("gml"), "Mittelniederdeutsch";
+    Gmw: "gmw", None, "Westgermanisch";
+    Gmy: "gmy", Some("gmy"), "Mykenisch";
+    Gn: "gn", Some("grn"), "Guaraní";
+    Gnc: "gnc", Some("gnc"), "Guanche";
+    Goh: "goh", Some("goh"), "Althochdeutsch";
+    Got: "got", Some("got"), "Gotisch";
+    Grc: "grc", Some("grc"), "Altgriechisch";
+    Gsw: "gsw", Some("gsw"), "Schweizerdeutsch";
+    Gu: "gu", Some("guj"), "Gujarati";
+    Gv: "gv", Some("glv"), "Manx";
+    Ha: "ha", Some("hau"), "Hausa";
+    Hac: "hac", Some("hac"), "Gorani";
+    Hak: "hak", Some("hak"), "Hakka";
+    Haw: "haw", Some("haw"), "Hawaiisch";
+    He: "he", Some("heb"), "Hebräisch";
+    Hi: "hi", Some("hin"), "Hindi";
+    Hif: "hif", Some("hif"), "Fidschi-Hindi";
+    Hit: "hit", Some("hit"), "Hethitisch";
+    Ho: "ho", Some("hmo"), "Hiri Motu";
+    Hop: "hop", Some("hop"), "Hopi";
+    Hr: "hr", Some("hrv"), "Kroatisch";
+    Hrx: "hrx", Some("hrx"), "Hunsrückisch";
+    Hsb: "hsb", Some("hsb"), "Obersorbisch";
+    Ht: "ht", Some("hat"), "Haitianisch";
+    Hu: "hu", Some("hun"), "Ungarisch";
+    Hy: "hy", Some("hye"), "Armenisch";
+    Hz: "hz", Some("her"), "Herero";
+    Ia: "ia", Some("ina"), "Interlingua";
+    Iba: "iba", Some("iba"), "Iban";
+    Id: "id", Some("ind"), "Indonesisch";
+    Ie: "ie", Some("ile"), "Interlingue";
+    Ig: "ig", Some("ibo"), "Igbo";
+    Ii: "ii", Some("iii"), "Yi";
+    Ik: "ik", Some("ipk"), "Inupiaq";
+    Ilo: "ilo", Some("ilo"), "Ilokano";
+    Ine: "ine", None, "Indogermanisch";
+    Inh: "inh", Some("inh"), "Inguschisch";
+    Io: "io", Some("ido"), "Ido";
+    Is: "is", Some("isl"), "Isländisch";
+    Ist: "ist", Some("ist"), "Istriotisch";
+    It: "it", Some("ita"), "Italienisch";
+    Itk: "itk", Some("itk"), "Judäo-Italienisch";
+    Itl: "itl", Some("itl"), "Itelmenisch";
+    Iu: "iu", Some("iku"), "Inuktitut";
+    Izh: "izh", Some("izh"), "Ischorisch";
+    Ja: "ja", Some("jpn"), "Japanisch";
+    Jbo: "jbo", Some("jbo"), "Lojban";
+    Jrb: "jrb", Some("jrb"), "Judäo-Arabisch";
+    Jv: "jv", Some("jav"), "Javanisch";
+    Ka: "ka", Some("kat"), "Georgisch";
+    Kaa: "kaa", Some("kaa"), "Karakalpakisch";
+    Kab: "kab", Some("kab"), "Kabylisch";
+    Kam: "kam", Some("kam"), "Kamba";
+    Kaw: "kaw", Some("kaw"), "Kawi";
+    Kbd: "kbd", Some("kbd"), "Kabardinisch";
+    Kca: "kca", Some("kca"), "Chantisch";
+    Kdr: "kdr", Some("kdr"), "Karaimisch";
+    Kg: "kg", Some("kon"), "Kongo, Kikongo";
+    Khb: "khb", Some("khb"), "Lü";
+    Ki: "ki", Some("kik"), "Kikuyu";
+    Kj: "kj", Some("kua"), "Kuanyama";
+    Kjh: "kjh", Some("kjh"), "Chakassisch";
+    Kk: "kk", Some("kaz"), "Kasachisch";
+    Kl: "kl", Some("kal"), "Kalaallisut; Grönländisch";
+    Kla: "kla", Some("kla"), "Klamath";
+    Km: "km", Some("khm"), "Khmer";
+    Kmr: "kmr", Some("kmr"), "Nordkurdisch", "Kurmandschi";
+    Kn: "kn", Some("kan"), "Kannada";
+    Ko: "ko", Some("kor"), "Koreanisch";
+    Koi: "koi", Some("koi"), "Komi-Permjakisch";
+    Kok: "kok", Some("kok"), "Konkani";
+    Kos: "kos", Some("kos"), "Kosraeanisch";
+    Kr: "kr", Some("kau"), "Kanuri";
+    Krc: "krc", Some("krc"), "Karatschai-Balkarisch";
+    Krl: "krl", Some("krl"), "Karelisch";
+    Ks: "ks", Some("kas"), "Kaschmirisch";
+    Ksh: "ksh", Some("ksh"), "Kölsch", "Ripuarisch";
+    Ku: "ku", Some("kur"), "Kurdisch";
+    Kum: "kum", Some("kum"), "Kumükisch";
+    Kv: "kv", Some("kom"), "Komi";
+    Kw: "kw", Some("cor"), "Kornisch";
+    Ky: "ky", Some("kir"), "Kirgisisch";
+    Kyh: "kyh", Some("kyh"), "Karok";
+    La: "la", Some("lat"), "Latein", "Lateinisch";
+    Lad: "lad", Some("lad"), "Judenspanisch";
+    Lb: "lb", Some("ltz"), "Luxemburgisch";
+    Lbe: "lbe", Some("lbe"), "Lakisch";
+    Ldn: "ldn", Some("ldn"), "Láadan";
+    Lep: "lep", Some("lep"), "Lepcha";
+    Lez: "lez", Some("lez"), "Lesgisch";
+    Lg: "lg", Some("lug"), "Ganda";
+    Li: "li", Some("lim"), "Limburgisch";
+    Lij: "lij", Some("lij"), "Ligurisch";
+    Liv: "liv", Some("liv"), "Livisch";
+    Lld: "lld", Some("lld"), "Ladinisch";
+    Llp: "llp", Some("llp"), "Nord-Efate";
+    Lmo: "lmo", Some("lmo"), "Lombardisch";
+    Ln: "ln", Some("lin"), "Lingala";
+    Lo: "lo", Some("lao"), "Laotisch";
+    Lou: "lou", Some("lou"), "Louisiana-Kreolisch";
+    Loz: "loz", Some("loz"), "Lozi";
+    Lt: "lt", Some("lit"), "Litauisch";
+    Ltg: "ltg", Some("ltg"), "Lettgallisch";
+    Lu: "lu", Some("lub"), "Kiluba (Luba-Katanga)";
+    Lud: "lud", Some("lud"), "Ludisch";
+    Lus: "lus", Some("lus"), "Mizo";
+    Lv: "lv", Some("lav"), "Lettisch";
+    Lzz: "lzz", Some("lzz"), "Lasisch";
+    Mad: "mad", Some("mad"), "Maduresisch";
+    Mak: "mak", Some("mak"), "Makassarisch";
+    Mas: "mas", Some("mas"), "Massai";
+    Mdf: "mdf", Some("mdf"), "Mokschanisch";
+    Mfe: "mfe", Some("mfe"), "Morisyen";
+    Mg: "mg", Some("mlg"), "Madagassisch";
+    Mga: "mga", Some("mga"), "Mittelirisch";
+    Mh: "mh", Some("mah"), "Marshallesisch";
+    Mhr: "mhr", Some("mhr"), "Wiesenmari";
+    Mi: "mi", Some("mri"), "Maori";
+    Mic: "mic", Some("mic"), "Micmac";
+    Min: "min", Some("min"), "Minangkabauisch";
+    Mk: "mk", Some("mkd"), "Mazedonisch";
+    Ml: "ml", Some("mal"), "Malayalam";
+    Mn: "mn", Some("mon"), "Mongolisch";
+    Mnc: "mnc", Some("mnc"), "Mandschurisch";
+    Mnk: "mnk", Some("mnk"), "Mandinka";
+    Mns: "mns", Some("mns"), "Mansisch";
+    Moh: "moh", Some("moh"), "Mohawk";
+    Mr: "mr", Some("mar"), "Marathi";
+    Mrj: "mrj", Some("mrj"), "Bergmari";
+    Ms: "ms", Some("msa"), "Malaiisch";
+    Mt: "mt", Some("mlt"), "Maltesisch";
+    Mul: "mul", Some("mul"), "International";
+    Mus: "mus", Some("mus"), "Muskogee";
+    Mwl: "mwl", Some("mwl"), "Mirandés";
+    My: "my", Some("mya"), "Birmanisch";
+    Myn: "myn", None, "Maya-Sprachen";
+    Myv: "myv", Some("myv"), "Ersjanisch";
+    Mzn: "mzn", Some("mzn"), "Masanderanisch";
+    Na: "na", Some("nau"), "Nauruisch";
+    Nah: "nah", Some("nah"), "Nahuatl";
+    Nan: "nan", Some("nan"), "Min Nan";
+    Nap: "nap", Some("nap"), "Neapolitanisch";
+    Naq: "naq", Some("naq"), "Nama";
+    Nb: "nb", Some("nob"), "Bokmål";
+    Nd: "nd", Some("nde"), "Nord-Ndebele";
+    Nds: "nds", Some("nds"), "Niederdeutsch", "Plattdeutsch";
+    Ne: "ne", Some("nep"), "Nepalesisch";
+    New: "new", Some("new"), "Newari";
+    Nez: "nez", Some("nez"), "Nez Percé";
+    Ng: "ng", Some("ndo"), "Ndonga";
+    Ngo: "ngo", Some("ngo"), "Ngoni";
+    Nhn: "nhn", Some("nhn"), "Zentral-Nahuatl";
+    Nic: "nic", None, "Niger-Kongo-Sprachen";
+    Niu: "niu", Some("niu"), "Niueanisch";
+    Nl: "nl", Some("nld"), "Niederländisch";
+    Nmn: "nmn", Some("nmn"), "ǃXóõ";
+    Nn: "nn", Some("nno"), "Nynorsk (Neunorwegisch)";
+    No: "no", Some("nor"), "Norwegisch";
+    Nog: "nog", Some("nog"), "Nogaisch";
+    Non: "non", Some("non"), "Altnordisch";
+    Nov: "nov", Some("nov"), "Novial";
+    Nr: "nr", Some("nbl"), "Süd-Ndebele";
+    Nrf: "nrf", Some("nrf"), "Normannisch";
+    Nso: "nso", Some("nso"), "Nord-Sotho";
+    Nv: "nv", Some("nav"), "Navajo";
+    Ny: "ny", Some("nya"), "Chichewa";
+    Nyn: "nyn", Some("nyn"), "Runyankore";
+    Obt: "obt", Some("obt"), "Altbretonisch";
+    Oc: "oc", Some("oci"), "Okzitanisch";
+    Oco: "oco", Some("oco"), "Altkornisch";
+    Odt: "odt", Some("odt"), "Altniederländisch";
+    Ofs: "ofs", Some("ofs"), "Altfriesisch";
+    Oj: "oj", Some("oji"), "Anishinabe";
+    Om: "om", Some("orm"), "Oromo";
+    Ood: "ood", Some("ood"), "O’odham";
+    Or: "or", Some("ori"), "Oriya";
+    Orv: "orv", Some("orv"), "Altostslawisch";
+    Os: "os", Some("oss"), "Ossetisch";
+    Osc: "osc", Some("osc"), "Oskisch";
+    Osx: "osx", Some("osx"), "Altsächsisch";
+    Ota: "ota", Some("ota"), "Osmanisch";
+    Owl: "owl", Some("owl"), "Altwalisisch";
+    Pa: "pa", Some("pan"), "Pandschabi";
+    Pag: "pag", Some("pag"), "Pangasinensisch";
+    Pal: "pal", Some("pal"), "Mittelpersisch";
+    Pam: "pam", Some("pam"), "Kapampangan";
+    Pap: "pap", Some("pap"), "Papiamentu";
+    Pcd: "pcd", Some("pcd"), "Pikardisch";
+    Pdc: "pdc", Some("pdc"), "Pennsylvaniadeutsch";
+    Pdt: "pdt", Some("pdt"), "Plautdietsch";
+    Peo: "peo", Some("peo"), "Altpersisch";
+    Pfl: "pfl", Some("pfl"), "Pfälzisch";
+    Pgn: "pgn", Some("pgn"), "Paelignisch";
+    Phn: "phn", Some("phn"), "Phönizisch";
+    Pi: "pi", Some("pli"), "Pali";
+    Pih: "pih", Some("pih"), "Pitkern";
+    Pis: "pis", Some("pis"), "Pijin";
+    Pl: "pl", Some("pol"), "Polnisch";
+    Pms: "pms", Some("pms"), "Piemontesisch";
+    Pnb: "pnb", Some("pnb"), "Westpanjabi";
+    Pnt: "pnt", Some("pnt"), "Pontisch";
+    Pox: "pox", Some("pox"), "Polabisch";
+    Pra: "pra", None, "Prakrit";
+    Prg: "prg", Some("prg"), "Altpreußisch";
+    Pro: "pro", Some("pro"), "Altokzitanisch";
+    Prs: "prs", Some("prs"), "Dari";
+    Ps: "ps", Some("pus"), "Paschtu";
+    Pt: "pt", Some("por"), "Portugiesisch";
+    Qu: "qu", Some("que"), "Quechua";
+    Raj: "raj", Some("raj"), "Rajasthani";
+    Rap: "rap", Some("rap"), "Rapanui";
+    Rhg: "rhg", Some("rhg"), "Rohingya";
+    Rif: "rif", Some("rif"), "Tarifit";
+    Rm: "rm", Some("roh"), "Rätoromanisch";
+    Rmq: "rmq", Some("rmq"), "Erromintxela";
+    Rmr: "rmr", Some("rmr"), "Caló";
+    Rmy: "rmy", Some("rmy"), "Vlax-Romani";
+    Rn: "rn", Some("run"), "Kirundi";
+    Ro: "ro" | "mo", Some("ron"), "Rumänisch";
+    Rom: "rom", Some("rom"), "Romani";
+    Ru: "ru", Some("rus"), "Russisch";
+    Rue: "rue", Some("rue"), "Russinisch";
+    Rup: "rup", Some("rup"), "Aromunisch";
+    Rw: "rw", Some("kin"), "Kinyarwanda";
+    Sa: "sa", Some("san"), "Sanskrit";
+    Sah: "sah", Some("sah"), "Jakutisch";
+    Sas: "sas", Some("sas"), "Sasak";
+    Sc: "sc", Some("srd"), "Sardisch";
+    Scn: "scn", Some("scn"), "Sizilianisch";
+    Sco: "sco", Some("sco"), "Scots";
+    Sd: "sd", Some("snd"), "Sindhi";
+    Se: "se", Some("sme"), "Samisch";
+    Sg: "sg", Some("sag"), "Sango";
+    Sga: "sga", Some("sga"), "Altirisch";
+    Sgs: "sgs", Some("sgs"), "Samogitisch";
+    Sgw: "sgw", Some("sgw"), "Sebat Bet Gurage";
+    Sh: "sh", Some("hbs"), "Serbokroatisch";
+    Shh: "shh", Some("shh"), "Shoshone";
+    Shi: "shi", Some("shi"), "Taschelhit";
+    Shv: "shv", Some("shv"), "Schahri";
+    Si: "si", Some("sin"), "Singhalesisch";
+    Simple: "simple", None, "Einfaches Englisch";
+    Sjn: "sjn", Some("sjn"), "Sindarin";
+    Sk: "sk", Some("slk"), "Slowakisch";
+    Sl: "sl", Some("slv"), "Slowenisch";
+    Sla: "sla", None, "Slawisch";
+    Sli: "sli", Some("sli"), "Schlesisch (deutsche Mundart)";
+    Sm: "sm", Some("smo"), "Samoanisch";
+    Smi: "smi", None, "Samische Sprachen";
+    Smn: "smn", Some("smn"), "Inarisamisch";
+    Sn: "sn", Some("sna"), "Shona";
+    Snk: "snk", Some("snk"), "Soninke";
+    So: "so", Some("som"), "Somali";
+    Spx: "spx", Some("spx"), "Südpikenisch";
+    Sq: "sq", Some("sqi"), "Albanisch";
+    Sqr: "sqr", Some("sqr"), "Siculo-Arabisch";
+    Sqt: "sqt", Some("sqt"), "Soqotri";
+    Sr: "sr", Some("srp"), "Serbisch";
+    Src: "src", Some("src"), "Logudoresisch";
+    Srn: "srn", Some("srn"), "Sranantongo";
+    Sro: "sro", Some("sro"), "Campidanesisch";
+    Srr: "srr", Some("srr"), "Serer";
+    Ss: "ss", Some("ssw"), "Siswati";
+    St: "st", Some("sot"), "Sesotho";
+    Stq: "stq", Some("stq"), "Saterfriesisch";
+    Su: "su", Some("sun"), "Sundanesisch";
+    Suw: "suw", Some("suw"), "Sumbwa";
+    Sux: "sux", Some("sux"), "Sumerisch";
+    Sv: "sv", Some("swe"), "Schwedisch";
+    Sva: "sva", Some("sva"), "Swanisch";
+    Sw: "sw", Some("swa"), "Swahili";
+    Swb: "swb", Some("swb"), "Komorisch";
+    Swg: "swg", Some("swg"), "Schwäbisch";
+    Syr: "syr", Some("syr"), "Syrisch";
+    Szl: "szl", Some("szl"), "Schlesisch";
+    Ta: "ta", Some("tam"), "Tamilisch";
+    Tay: "tay", Some("tay"), "Atayal";
+    Te: "te", Some("tel"), "Telugu";
+    Tet: "tet", Some("tet"), "Tetum";
+    Tg: "tg", Some("tgk"), "Tadschikisch";
+    Th: "th", Some("tha"), "Thailändisch";
+    Ti: "ti", Some("tir"), "Tigrinya";
+    Tig: "tig", Some("tig"), "Tigre";
+    Tk: "tk", Some("tuk"), "Turkmenisch";
+    Tkl: "tkl", Some("tkl"), "Tokelauisch";
+    Tl: "tl", Some("tgl"), "Tagalog";
+    Tlh: "tlh", Some("tlh"), "Klingonisch";
+    Tmh: "tmh", Some("tmh"), "Tamascheq";
+    Tn: "tn", Some("tsn"), "Setswana";
+    Tnq: "tnq", Some("tnq"), "Taíno";
+    To: "to", Some("ton"), "Tongaisch";
+    Tokipona: "tokipona", Some("tok"), "Toki Pona";
+    Tox: "tox", Some("tox"), "Tobianisch";
+    Tpi: "tpi", Some("tpi"), "Tok Pisin";
+    Tpn: "tpn", Some("tpn"), "Tupinambá";
+    Tpw: "tpw", Some("tpw"), "Tupí";
+    Tr: "tr", Some("tur"), "Türkisch";
+    Trv: "trv", Some("trv"), "Taroko";
+    Ts: "ts", Some("tso"), "Tsonga";
+    Tt: "tt", Some("tat"), "Tatarisch";
+    Tum: "tum", Some("tum"), "Tumbuka";
+    Tvk: "tvk", Some("tvk"), "Südost-Ambrym";
+    Tvl: "tvl", Some("tvl"), "Tuvaluisch";
+    Tw: "tw", Some("twi"), "Twi";
+    Txb: "txb", Some("txb"), "Tocharisch B";
+    Txh: "txh", Some("txh"), "Thrakisch";
+    Ty: "ty", Some("tah"), "Tahitianisch";
+    Tyv: "tyv", Some("tyv"), "Tuwinisch";
+    Tzl: "tzl", Some("tzl"), "Talossanisch";
+    Tzm: "tzm", Some("tzm"), "Zentralatlas-Tamazight";
+    Udm: "udm", Some("udm"), "Udmurtisch";
+    Ug: "ug", Some("uig"), "Uigurisch";
+    Uga: "uga", Some("uga"), "Ugaritisch";
+    Uk: "uk", Some("ukr"), "Ukrainisch";
+    Umc: "umc", Some("umc"), "Marrukinisch";
+    Ur: "ur", Some("urd"), "Urdu";
+    Uum: "uum", Some("uum"), "Urum";
+    Uz: "uz", Some("uzb"), "Usbekisch";
+    Ve: "ve", Some("ven"), "Venda";
+    Vec: "vec", Some("vec"), "Venezianisch";
+    Vep: "vep", Some("vep"), "Wepsisch";
+    Vi: "vi", Some("vie"), "Vietnamesisch";
+    Vls: "vls", Some("vls"), "Westflämisch";
+    Vmf: "vmf", Some("vmf"), "Mainfränkisch";
+    Vo: "vo", Some("vol"), "Volapük";
+    Vot: "vot", Some("vot"), "Wotisch";
+    Vro: "vro", Some("vro"), "Võro";
+    Wa: "wa", Some("wln"), "Wallonisch";
+    Wae: "wae", Some("wae"), "Walser";
+    War: "war", Some("war"), "Wáray-Wáray";
+    Wen: "wen", None, "Sorbisch";
+    Wep: "wep", Some("wep"), "Westfälisch";
+    Wlm: "wlm", Some("wlm"), "Mittelwalisisch";
+    Wo: "wo", Some("wol"), "Wolof";
+    Wuu: "wuu", Some("wuu"), "Wu";
+    Wym: "wym", Some("wym"), "Wilmesaurisch";
+    Xaa: "xaa", Some("xaa"), "Andalusisch-Arabisch";
+    Xal: "xal", Some("xal"), "Kalmückisch";
+    Xcl: "xcl", Some("xcl"), "Altarmenisch";
+    Xfa: "xfa", Some("xfa"), "Faliskisch";
+    Xh: "xh", Some("xho"), "isiXhosa";
+    Xhu: "xhu", Some("xhu"), "Hurritisch";
+    Xlc: "xlc", Some("xlc"), "Lykisch";
+    Xld: "xld", Some("xld"), "Lydisch";
+    Xlu: "xlu", Some("xlu"), "Luwisch";
+    Xmf: "xmf", Some("xmf"), "Mingrelisch";
+    Xmn: "xmn", Some("xmn"), "Manichäisches Mittelpersisch";
+    Xno: "xno", Some("xno"), "Anglonormannisch";
+    Xum: "xum", Some("xum"), "Umbrisch";
+    Xur: "xur", Some("xur"), "Urartäisch";
+    Xve: "xve", Some("xve"), "Venetisch";
+    Yi: "yi", Some("yid"), "Jiddisch";
+    Yo: "yo", Some("yor"), "Yoruba";
+    Yua: "yua", Some("yua"), "Yukatekisch";
+    Yue: "yue", Some("yue"), "Kantonesisch";
+    Za: "za", Some("zha"), "Zhuang";
+    Zbw: "zbw", Some("zbw"), "West-Berawan";
+    Zea: "zea", Some("zea"), "Seeländisch", "Zeeländisch";
+    Zen: "zen", Some("zen"), "Zenaga";
+    Zh: "zh", Some("zho"), "Chinesisch";
+    ZhCn: "zh-cn", None, "Chinesisch (vereinfacht)";
+    ZhTw: "zh-tw", None, "Chinesisch (traditionell)";
+    Zu: "zu", Some("zul"), "isiZulu";
+    Zza: "zza", Some("zza"), "Zazaki";
 }
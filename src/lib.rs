@@ -48,9 +48,9 @@
 //!
 //! # Limitations
 //!
-//! Parameters of overview templates are transferred to the output with minimal validation and processing. Due to the wide variety of overview templates that take parameters in highly complicated and inconsistent formats, fully validating and parsing these parameters is not feasible.
+//! Parameters of overview templates are transferred to the output with minimal validation and processing. Due to the wide variety of overview templates that take parameters in highly complicated and inconsistent formats, fully validating and parsing these parameters is not feasible. Only the most common overview templates are additionally parsed into typed fields, such as [`NounInflection`](struct.NounInflection.html) for German nouns, [`VerbOverview`](struct.VerbOverview.html) for German verbs and [`Comparison`](struct.Comparison.html) for German and English adjectives.
 //!
-//! The translations in the template [`Vorlage:Ü-Tabelle`](Vorlage:Ü-Tabelle) in the section [`Übersetzungen`](https://de.wiktionary.org/wiki/Vorlage:%C3%9Cbersetzungen) are not parsed. Due to the highly complicated format of translations, it's better not to even try parsing them than try and get an inconsistent result. Due to the common presence of translation tables that contain empty translations, it's not even indicated whether an entry has translations.
+//! The translations in the template [`Ü-Tabelle`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C-Tabelle) in the section [`Übersetzungen`](https://de.wiktionary.org/wiki/Vorlage:%C3%9Cbersetzungen) are parsed only as far as their format is consistent. Each line is split into the language and the groups of translations following each sense reference, but the translations themselves are only parsed as the elements they consist of. Translations that are left empty in the table are not included in the output. Lines that don't follow the expected format are left out of the output and cause a warning.
 //!
 //! The templates [`Ähnlichkeiten 1`](https://de.wiktionary.org/wiki/Vorlage:%C3%84hnlichkeiten_1) and [`Ähnlichkeiten 2`](https://de.wiktionary.org/wiki/Vorlage:%C3%84hnlichkeiten_2) are not parsed, because it's unclear what purpose they have and what format their parameters must have.
 
@@ -59,28 +59,291 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+extern crate md5;
 extern crate parse_wiki_text;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 
+mod audio_sample;
+mod comparison;
+mod compound;
 mod configuration;
+mod conjugation;
+mod declension;
+mod definitions;
+mod etymology;
 mod examples;
+mod flexion;
+mod form_index;
+mod form_of;
+mod hyphenation;
+mod ipa;
+mod labels;
 mod language;
 mod languages;
 mod list;
+mod noun_inflection;
 mod overview;
+mod pos;
 mod pos_section;
 mod pos_template;
 mod pronunciation;
+mod rhyme_index;
+mod rhyme_page;
+mod senses;
+mod translations;
 mod util;
+mod verb_overview;
+mod word_formations;
 
 pub use configuration::create_configuration;
-pub use languages::Language;
+pub use ipa::tokenize_ipa;
+pub use labels::{Label, LabelKind, LabelReference};
+pub use languages::{Language, LanguageReference};
+pub use pos::Pos;
 use parse_wiki_text::{DefinitionListItem, DefinitionListItemType::Details, Node, Parameter};
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+};
 use util::*;
 
+/// Audio sample of a form of the word.
+///
+/// Parsed from the template [`Audio`](https://de.wiktionary.org/wiki/Vorlage:Audio) in the subsection [`Hörbeispiele`](https://de.wiktionary.org/wiki/Vorlage:H%C3%B6rbeispiele) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AudioSample<'a> {
+    /// The name of the file on Wikimedia Commons.
+    pub file_name: Cow<'a, str>,
+
+    /// The form pronounced, from the template such as [`Pl.`](https://de.wiktionary.org/wiki/Vorlage:Pl.) preceding the audio sample, or `None` for the lemma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form: Option<WordForm>,
+
+    /// The label to display for the audio sample.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Cow<'a, str>>,
+
+    /// The language of the audio sample, from the parameter `spr`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<LanguageReference<'a>>,
+
+    /// The region where the pronunciation is used, from the prefix of the file name such as `De-at-` or `De-ch-`, or otherwise from a regional label preceding the audio sample.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Label>,
+}
+
+/// Grammatical case.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Case {
+    /// Accusative case (“Akkusativ”)
+    Accusative,
+
+    /// Dative case (“Dativ”)
+    Dative,
+
+    /// Genitive case (“Genitiv”)
+    Genitive,
+
+    /// Nominative case (“Nominativ”)
+    Nominative,
+}
+
+/// Comparison of an adjective.
+///
+/// Parsed from the templates [`Deutsch Adjektiv Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Adjektiv_%C3%9Cbersicht) and [`Englisch Adjektiv Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Englisch_Adjektiv_%C3%9Cbersicht). Each field contains the form given by the parameter named in its documentation, followed by any variants given by the same parameter name followed by `*` or a number. Forms that are given as a dash are left out.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Comparison<'a> {
+    /// Comparative, from the parameter `Komparativ`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comparative: Vec<OverviewForm<'a>>,
+
+    /// Positive, from the parameter `Positiv`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub positive: Vec<OverviewForm<'a>>,
+
+    /// Superlative preceded by “am”, as used predicatively in German, from the parameter `Superlativ`.
+    ///
+    /// This is only given for German adjectives, and contains one form for each form in `superlative`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub predicative_superlative: Vec<OverviewForm<'a>>,
+
+    /// Superlative, from the parameter `Superlativ`.
+    ///
+    /// For German adjectives this is the form without the word “am”, such as “schönsten”.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub superlative: Vec<OverviewForm<'a>>,
+}
+
+/// Word formation of a German word, recognized from its etymology.
+///
+/// Returned by [`Etymology::compound`](struct.Etymology.html#method.compound).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Compound<'a> {
+    /// The words the word is formed from, in the order they are given.
+    pub constituents: Vec<Constituent<'a>>,
+
+    /// The kind of word formation.
+    pub kind: CompoundKind,
+
+    /// The linking element (“Fugenelement”) between the constituents if any, from the target of the link to it, such as `-s`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linking_element: Option<Cow<'a, str>>,
+}
+
+/// Kind of word formation.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompoundKind {
+    /// Word class conversion (“Konversion”)
+    Conversion,
+
+    /// Copulative compound (“Kopulativkompositum”)
+    Copulative,
+
+    /// Derivation (“Ableitung”)
+    Derivation,
+
+    /// Determinative compound (“Determinativkompositum”)
+    Determinative,
+
+    /// Juxtaposition (“Zusammenrückung”)
+    Juxtaposition,
+}
+
+/// Word that another word is formed from.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Constituent<'a> {
+    /// The lemma of the constituent, from the target of the link to it or from italic text.
+    pub lemma: Cow<'a, str>,
+
+    /// The part of speech of the constituent, from the word preceding it, such as “Substantiv” or “Suffix”, or `None` if not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<Pos>,
+}
+
+/// Declension of an attributive adjective.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Declension {
+    /// Mixed declension (“gemischte Deklination”), following an indefinite article or a possessive pronoun
+    Mixed,
+
+    /// Strong declension (“starke Deklination”), without a preceding article
+    Strong,
+
+    /// Weak declension (“schwache Deklination”), following a definite article
+    Weak,
+}
+
+/// Definition of a sense of the entry.
+///
+/// Parsed from a list item in the section [`Bedeutungen`](https://de.wiktionary.org/wiki/Vorlage:Bedeutungen).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Definition<'a> {
+    /// The content of the definition, following the sense reference.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gloss: Vec<Flowing<'a>>,
+
+    /// Context labels of the definition, from the templates at the start of the definition that are parsed as [`Flowing::Context`](enum.Flowing.html#variant.Context).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<LabelReference<'a>>,
+
+    /// The sense defined, from the reference in brackets at the start of the list item.
+    ///
+    /// A reference that consists of only a letter, such as `[a]` in a list nested under the sense `[2]`, takes its number from the sense it's nested under. If the list item doesn't start with a reference to a single sense, this is `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sense: Option<Sense>,
+
+    /// Definitions of sub-senses of the sense.
+    ///
+    /// Parsed from a list nested in the list item, and from subsequent list items at the same level that refer to a sub-sense of this sense, such as `[2a]` following `[2]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subsenses: Vec<Definition<'a>>,
+}
+
+/// Degree of comparison of an adjective.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Degree {
+    /// Comparative (“Komparativ”)
+    Comparative,
+
+    /// Positive (“Positiv”)
+    Positive,
+
+    /// Superlative (“Superlativ”)
+    Superlative,
+}
+
+/// Auxiliary verb used to form the perfect tenses of a verb.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Auxiliary {
+    /// The auxiliary verb “sein”.
+    Be,
+
+    /// The auxiliary verb “haben”.
+    Have,
+}
+
+/// Etymology, from a list item in the section [`Herkunft`](https://de.wiktionary.org/wiki/Vorlage:Herkunft).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Etymology<'a> {
+    /// The etymology as written, following the sense reference if any.
+    ///
+    /// This is kept even when the etymology could be classified, and is the only information available when it couldn't.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub content: Vec<Flowing<'a>>,
+
+    /// The senses the etymology refers to, from the reference in brackets at the start of the list item.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senses: Vec<Sense>,
+
+    /// The steps of the etymology in the order they are written, which is usually from the word itself back to its earliest known origin.
+    ///
+    /// Consecutive steps with the same relation and no language, such as the parts of a compound, are the parts the word is made of. Empty if the etymology could not be classified.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<EtymologyStep<'a>>,
+}
+
+/// Relation between a word and a form it originates from.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EtymologyRelation {
+    /// Borrowed from another language (“Entlehnung”)
+    Borrowed,
+
+    /// Part of a compound (“Kompositum”)
+    Compound,
+
+    /// Derived from a word in the same language (“Ableitung”)
+    Derived,
+
+    /// Inherited from an earlier stage of the language (“Erbwort”)
+    Inherited,
+}
+
+/// Step in an etymology, naming a form the word originates from.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EtymologyStep<'a> {
+    /// The cited form, from italic text, a link in italics or the template [`Ü`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C).
+    pub form: Cow<'a, str>,
+
+    /// The meaning of the cited form, from the text in quotation marks following it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gloss: Option<Cow<'a, str>>,
+
+    /// The language of the cited form, or `None` if it's not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<LanguageReference<'a>>,
+
+    /// The relation between the word and the cited form.
+    pub relation: EtymologyRelation,
+}
+
 /// Usage example.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Example<'a> {
@@ -88,11 +351,73 @@ pub struct Example<'a> {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub example: Vec<Flowing<'a>>,
 
+    /// The senses the example illustrates, from the reference in brackets at the start of the example.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senses: Vec<Sense>,
+
     /// The German translation of the example.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub translation: Vec<Flowing<'a>>,
 }
 
+/// Grammatical features of an inflected form.
+///
+/// Features that don't apply to the form are left out.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Features {
+    /// The case of the form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case: Option<Case>,
+
+    /// The declension of the form, for attributive adjectives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declension: Option<Declension>,
+
+    /// The degree of comparison of the form, for adjectives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub degree: Option<Degree>,
+
+    /// The gender of the form. For adjectives this is only given in singular.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+
+    /// The mood of the form, for finite verb forms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mood: Option<Mood>,
+
+    /// The kind of non-finite verb form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_finite: Option<NonFinite>,
+
+    /// The number of the form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<Number>,
+
+    /// The person of the form, for finite verb forms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person: Option<Person>,
+
+    /// The tense of the form, for finite verb forms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tense: Option<Tense>,
+
+    /// The voice of the form, for finite verb forms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+}
+
+/// Output of parsing a page in the namespace `Flexion`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FlexionOutput<'a> {
+    /// The paradigms given by the inflection templates on the page, in the order they occur.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paradigms: Vec<Paradigm<'a>>,
+
+    /// Warnings from the parser telling that something is not well-formed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+}
+
 /// An element in a sequence that allows different kinds of elements.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
@@ -110,7 +435,7 @@ pub enum Flowing<'a> {
 
         /// The language of the audio.
         #[serde(skip_serializing_if = "Option::is_none")]
-        language: Option<Cow<'a, str>>,
+        language: Option<LanguageReference<'a>>,
     },
 
     /// Toggle bold text.
@@ -133,6 +458,14 @@ pub enum Flowing<'a> {
     /// Parsed from the template [`Komp.`](https://de.wiktionary.org/wiki/Vorlage:Komp.).
     Comparative,
 
+    /// Context labels.
+    ///
+    /// Parsed from the template [`K`](https://de.wiktionary.org/wiki/Vorlage:K) or a template named after the abbreviation of a label, such as [`ugs.`](https://de.wiktionary.org/wiki/Vorlage:ugs.). The free text given in the parameter `ft` of the template `K` is included as an unknown label. The parameter `spr`, which only affects the categories the page is put in, and the separator parameters `t1`, `t2` and so on are not included.
+    Context {
+        /// The labels in order.
+        labels: Vec<LabelReference<'a>>,
+    },
+
     /// Placeholder for an audio sample that has not been filled in.
     ///
     /// Parsed from the template [`Audio`](https://de.wiktionary.org/wiki/Vorlage:Audio).
@@ -166,7 +499,7 @@ pub enum Flowing<'a> {
     /// Parsed from the templates found in the category [`Sprachkürzel`](https://de.wiktionary.org/wiki/Kategorie:Wiktionary:Sprachk%C3%BCrzel).
     Language {
         /// The language referred to.
-        language: Cow<'a, str>,
+        language: LanguageReference<'a>,
     },
 
     /// Language as an adjective.
@@ -174,7 +507,7 @@ pub enum Flowing<'a> {
     /// Parsed from the templates found in the category [`Sprachadjektive`](https://de.wiktionary.org/wiki/Kategorie:Wiktionary:Sprachadjektive).
     LanguageAdjective {
         /// The language referred to.
-        language: Cow<'a, str>,
+        language: LanguageReference<'a>,
     },
 
     /// Link.
@@ -210,6 +543,11 @@ pub enum Flowing<'a> {
     /// Parsed from the template [`kPl.`](https://de.wiktionary.org/wiki/Vorlage:kPl.).
     NoPlural,
 
+    /// Indication that something has no singular.
+    ///
+    /// Parsed from the template [`kSg.`](https://de.wiktionary.org/wiki/Vorlage:kSg.).
+    NoSingular,
+
     /// Indication that something is a past participle.
     ///
     /// Parsed from the template [`Part.`](https://de.wiktionary.org/wiki/Vorlage:Part.).
@@ -291,7 +629,7 @@ pub enum Flowing<'a> {
     /// Parsed from the template [`Ü`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C).
     Term {
         /// The language the link refers to.
-        language: Cow<'a, str>,
+        language: LanguageReference<'a>,
 
         /// The term the link refers to.
         term: Cow<'a, str>,
@@ -314,6 +652,152 @@ pub enum Flowing<'a> {
     },
 }
 
+/// Reference from an inflected form to its lemma.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct FormOf<'a> {
+    /// The grammatical features of the form, one for each list item in the section [`Grammatische Merkmale`](https://de.wiktionary.org/wiki/Vorlage:Grammatische_Merkmale), such as “Nominativ Plural des Substantivs”.
+    ///
+    /// List items that can't be parsed are left out and cause a warning.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<Features>,
+
+    /// The lemma, from the link in the list items in the section [`Grammatische Merkmale`](https://de.wiktionary.org/wiki/Vorlage:Grammatische_Merkmale).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lemma: Option<Cow<'a, str>>,
+
+    /// The part of speech of the lemma, from the list items in the section [`Grammatische Merkmale`](https://de.wiktionary.org/wiki/Vorlage:Grammatische_Merkmale), such as “des Substantivs”.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<Pos>,
+
+    /// The title of the page of the lemma, from the first parameter of the template [`Grundformverweis Dekl`](https://de.wiktionary.org/wiki/Vorlage:Grundformverweis_Dekl) or [`Grundformverweis Konj`](https://de.wiktionary.org/wiki/Vorlage:Grundformverweis_Konj).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<Cow<'a, str>>,
+}
+
+/// Index from inflected forms to their lemmas and grammatical features.
+///
+/// The index is built from parsed pages by adding them one at a time, and can be serialized to be used without the parsed pages.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct FormIndex {
+    /// The lemmas and features of each form, by form.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub forms: BTreeMap<String, Vec<FormIndexEntry>>,
+}
+
+/// Lemma and grammatical features of a form in a [`FormIndex`](struct.FormIndex.html).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FormIndexEntry {
+    /// The grammatical features of the form.
+    pub features: Features,
+
+    /// The language of the lemma.
+    pub language: Language,
+
+    /// The lemma, which is the title of the page of the entry the form belongs to.
+    pub lemma: String,
+
+    /// The part of speech of the lemma.
+    pub pos: Pos,
+}
+
+/// Grammatical gender.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    /// Common gender (“Utrum”)
+    Common,
+
+    /// Feminine gender (“Femininum”)
+    Feminine,
+
+    /// Masculine gender (“Maskulinum”)
+    Masculine,
+
+    /// Neuter gender (“Neutrum”)
+    Neuter,
+}
+
+/// Hyphenation of a form of the word.
+///
+/// Parsed from a line in the section [`Worttrennung`](https://de.wiktionary.org/wiki/Vorlage:Worttrennung), which gives the hyphenation of the lemma followed by the hyphenation of other forms, each following a template such as [`Pl.`](https://de.wiktionary.org/wiki/Vorlage:Pl.).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Hyphenation<'a> {
+    /// Alternative hyphenations of the form, each split into syllables at `·`.
+    ///
+    /// Forms made up of several words, such as `am schöns·ten`, keep the spaces between the words within the syllables.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<Vec<Cow<'a, str>>>,
+
+    /// The form hyphenated, or `None` for the lemma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form: Option<WordForm>,
+
+    /// Whether the form doesn't exist, as indicated by the template [`kPl.`](https://de.wiktionary.org/wiki/Vorlage:kPl.) or [`kSg.`](https://de.wiktionary.org/wiki/Vorlage:kSg.), the text `kein Plural` or a dash.
+    ///
+    /// Alternatives may still be given for a missing form following a qualifier in italics, as in `{{kPl.}}, ''selten:'' Häu·ser`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub missing: bool,
+}
+
+/// Inflected form in a paradigm.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InflectedForm {
+    /// The grammatical features of the form.
+    pub features: Features,
+
+    /// The form. Forms made up of several words, such as compound tenses and verbs with a separable prefix, are written with the words separated by spaces in main clause word order.
+    pub form: String,
+}
+
+/// Token in a pronunciation written in IPA.
+///
+/// Returned by [`tokenize_ipa`](fn.tokenize_ipa.html).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpaToken<'a> {
+    /// Half-long mark `ˑ`.
+    HalfLong,
+
+    /// Hyphen, indicating that the pronunciation is of an affix or of part of a word.
+    Hyphen,
+
+    /// Character that is not valid in IPA.
+    Invalid(char),
+
+    /// Linking mark `‿`.
+    Linking,
+
+    /// Length mark `ː`.
+    Long,
+
+    /// Major prosodic break `‖`, such as the end of an intonation group.
+    MajorBreak,
+
+    /// Minor prosodic break `|`, such as between feet.
+    MinorBreak,
+
+    /// Closing parenthesis, ending a sound that may be left out.
+    OptionalEnd,
+
+    /// Opening parenthesis, starting a sound that may be left out.
+    OptionalStart,
+
+    /// Primary stress mark `ˈ`.
+    PrimaryStress,
+
+    /// Secondary stress mark `ˌ`.
+    SecondaryStress,
+
+    /// Phoneme segment, consisting of a letter followed by any diacritics and modifier letters, or of letters joined by a tie bar.
+    Segment(&'a str),
+
+    /// Syllable boundary `.`.
+    SyllableBoundary,
+
+    /// Space between words.
+    WordBoundary,
+}
+
 /// Dictionary entry for a single language.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct LanguageEntry<'a> {
@@ -327,6 +811,113 @@ pub struct LanguageEntry<'a> {
     pub pos_entries: Vec<PosEntry<'a>>,
 }
 
+/// Item in a list in a section of the POS entry.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListItem<'a> {
+    /// The content of the list item, following the sense reference if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub content: Vec<Flowing<'a>>,
+
+    /// The linked terms in the content, one relation per link.
+    ///
+    /// Only given in the sections for lexical relations, such as synonyms, antonyms, hypernyms, hyponyms and variants, not in sections such as idioms, proverbs, typical word combinations and compound words, where a link is not a relation to the word.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<Relation<'a>>,
+
+    /// The senses the list item refers to, from the reference in brackets at the start of the list item, such as `[1]`, `[1, 2]` or `[1–3]`.
+    ///
+    /// Lists and ranges of senses are expanded to each sense they include.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senses: Vec<Sense>,
+}
+
+/// Grammatical mood.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mood {
+    /// Imperative (“Imperativ”)
+    Imperative,
+
+    /// Indicative (“Indikativ”)
+    Indicative,
+
+    /// Subjunctive I (“Konjunktiv I”)
+    SubjunctiveI,
+
+    /// Subjunctive II (“Konjunktiv II”)
+    SubjunctiveIi,
+}
+
+/// Non-finite verb form.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NonFinite {
+    /// Infinitive (“Infinitiv”)
+    Infinitive,
+
+    /// Past participle (“Partizip II”)
+    PastParticiple,
+
+    /// Present participle (“Partizip I”)
+    PresentParticiple,
+
+    /// Infinitive with `zu` (“erweiterter Infinitiv”)
+    ZuInfinitive,
+}
+
+/// Declension of a German noun.
+///
+/// Parsed from the template [`Deutsch Substantiv Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Substantiv_%C3%9Cbersicht). Forms that are given as a dash are left out.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct NounInflection<'a> {
+    /// Accusative plural, from the parameters `Akkusativ Plural`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accusative_plural: Vec<OverviewForm<'a>>,
+
+    /// Accusative singular, from the parameters `Akkusativ Singular`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accusative_singular: Vec<OverviewForm<'a>>,
+
+    /// Dative plural, from the parameters `Dativ Plural`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dative_plural: Vec<OverviewForm<'a>>,
+
+    /// Dative singular, from the parameters `Dativ Singular`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dative_singular: Vec<OverviewForm<'a>>,
+
+    /// Genders ordered by the number in the parameter name, from the parameters `Genus`, `Genus 1`, `Genus 2` and so on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genders: Vec<Gender>,
+
+    /// Genitive plural, from the parameters `Genitiv Plural`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genitive_plural: Vec<OverviewForm<'a>>,
+
+    /// Genitive singular, from the parameters `Genitiv Singular`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genitive_singular: Vec<OverviewForm<'a>>,
+
+    /// Nominative plural, from the parameters `Nominativ Plural`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nominative_plural: Vec<OverviewForm<'a>>,
+
+    /// Nominative singular, from the parameters `Nominativ Singular`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nominative_singular: Vec<OverviewForm<'a>>,
+}
+
+/// Grammatical number.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Number {
+    /// Plural (“Plural”)
+    Plural,
+
+    /// Singular (“Singular”)
+    Singular,
+}
+
 /// Output of parsing a page.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Output<'a> {
@@ -358,75 +949,51 @@ pub struct Overview<'a> {
     pub unnamed_parameters: Vec<Vec<::Flowing<'a>>>,
 }
 
-/// Part of speech.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(rename_all = "snake_case")]
-pub enum Pos {
-    /// Abbreviation (“Abkürzung”)
-    Abbreviation,
-
-    /// Adjective (“Adjektiv”)
-    Adjective,
-
-    /// Adverb (“Adverb”)
-    Adverb,
-
-    /// Compound word (“Wortverbindung”)
-    CompoundWord,
-
-    /// Conjugated form (“Konjugierte Form”)
-    ConjugatedForm,
-
-    /// Conjunction (“Konjunktion”)
-    Conjunction,
-
-    /// Declined form (“Deklinierte Form”)
-    DeclinedForm,
-
-    /// First name (“Vorname”)
-    FirstName,
-
-    /// Idiom (“Redewendung”)
-    Idiom,
-
-    /// Interjection (“Interjektion”)
-    Interjection,
-
-    /// Last name (“Nachname”)
-    LastName,
-
-    /// Local adverb (“Lokaladverb”)
-    LocalAdverb,
-
-    /// Noun (“Substantiv”)
-    Noun,
+/// Form given by a parameter of an overview template, such as a noun in a particular case and number or a principal form of a verb.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OverviewForm<'a> {
+    /// Whether the form is an alternative to the other forms, from a parameter name ending with `*`, such as `Genitiv Singular*`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub alternative: bool,
 
-    /// Numeral (“Numerale”)
-    Numeral,
+    /// The form.
+    pub form: Cow<'a, str>,
 
-    /// Past participle (“Partizip II”)
-    PastParticiple,
-
-    /// Postposition (“Postposition”)
-    Postposition,
+    /// The number of the variant, from a parameter name ending with a number, such as `Nominativ Plural 2`.
+    ///
+    /// For a noun in the singular, the variant corresponds to the genus with the same number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<u8>,
+}
 
-    /// Preposition (“Präposition”)
-    Preposition,
+/// Complete table of the inflected forms of a word.
+///
+/// Parsed from an inflection template on a page in the namespace `Flexion`. The forms are generated from the parameters of the template in the same way as the template generates them. Forms that can't be derived unambiguously are left out.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Paradigm<'a> {
+    /// The inflected forms.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forms: Vec<InflectedForm>,
 
-    /// Proper noun (“Eigenname”)
-    ProperNoun,
+    /// The part of speech the template is for.
+    pub pos: Pos,
 
-    /// Proverb (“Sprichwort”)
-    Proverb,
+    /// The name of the inflection template.
+    pub template: Cow<'a, str>,
+}
 
-    /// Symbol (“Symbol”)
-    Symbol,
+/// Grammatical person.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Person {
+    /// First person
+    First,
 
-    /// Toponym (“Toponym”)
-    Toponym,
+    /// Second person
+    Second,
 
-    /// Verb (“Verb”)
-    Verb,
+    /// Third person
+    Third,
 }
 
 /// The entry for a part of speech within the entry for a language.
@@ -434,27 +1001,31 @@ pub enum Pos {
 pub struct PosEntry<'a> {
     /// Abbreviations, from the section [`Abkürzungen`](https://de.wiktionary.org/wiki/Vorlage:Abk%C3%BCrzungen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub abbreviations: Vec<Vec<Flowing<'a>>>,
+    pub abbreviations: Vec<ListItem<'a>>,
 
     /// Affectionate forms, from the section [`Koseformen`](https://de.wiktionary.org/wiki/Vorlage:Koseformen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub affectionate_forms: Vec<Vec<Flowing<'a>>>,
+    pub affectionate_forms: Vec<ListItem<'a>>,
 
     /// Antonyms, from the section [`Gegenwörter`](https://de.wiktionary.org/wiki/Vorlage:Gegenw%C3%B6rter).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub antonyms: Vec<Vec<Flowing<'a>>>,
+    pub antonyms: Vec<ListItem<'a>>,
 
-    /// Audio, from the subsection [`Hörbeispiele`](https://de.wiktionary.org/wiki/Vorlage:H%C3%B6rbeispiele) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
+    /// Audio samples of the lemma and other forms, from the subsection [`Hörbeispiele`](https://de.wiktionary.org/wiki/Vorlage:H%C3%B6rbeispiele) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub audio: Vec<Flowing<'a>>,
+    pub audio: Vec<AudioSample<'a>>,
+
+    /// Comparison of an adjective, from the overview template [`Deutsch Adjektiv Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Adjektiv_%C3%9Cbersicht) or [`Englisch Adjektiv Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Englisch_Adjektiv_%C3%9Cbersicht).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<Comparison<'a>>,
 
     /// Compound words, from the section [`Wortbildungen`](https://de.wiktionary.org/wiki/Vorlage:Wortbildungen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub compound_words: Vec<Vec<Flowing<'a>>>,
+    pub compound_words: Vec<ListItem<'a>>,
 
-    /// Definition, from the section [`Bedeutungen`](https://de.wiktionary.org/wiki/Vorlage:Bedeutungen).
+    /// Definitions by sense, from the section [`Bedeutungen`](https://de.wiktionary.org/wiki/Vorlage:Bedeutungen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub definitions: Vec<Vec<Flowing<'a>>>,
+    pub definitions: Vec<Definition<'a>>,
 
     /// Various details from the POS heading.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -462,11 +1033,11 @@ pub struct PosEntry<'a> {
 
     /// Diminutives, from the section [`Verkleinerungsformen`](https://de.wiktionary.org/wiki/Vorlage:Verkleinerungsformen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub diminutives: Vec<Vec<Flowing<'a>>>,
+    pub diminutives: Vec<ListItem<'a>>,
 
     /// Etymology, from the section [`Herkunft`](https://de.wiktionary.org/wiki/Vorlage:Herkunft).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub etymology: Vec<Vec<Flowing<'a>>>,
+    pub etymology: Vec<Etymology<'a>>,
 
     /// Examples, from the section [`Beispiele`](https://de.wiktionary.org/wiki/Vorlage:Beispiele).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -474,50 +1045,69 @@ pub struct PosEntry<'a> {
 
     /// Feminine forms, from the section [`Weibliche Wortformen`](https://de.wiktionary.org/wiki/Vorlage:Weibliche_Wortformen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub feminine_forms: Vec<Vec<Flowing<'a>>>,
+    pub feminine_forms: Vec<ListItem<'a>>,
+
+    /// Reference from an inflected form to its lemma, from the section [`Grammatische Merkmale`](https://de.wiktionary.org/wiki/Vorlage:Grammatische_Merkmale) and the template [`Grundformverweis Dekl`](https://de.wiktionary.org/wiki/Vorlage:Grundformverweis_Dekl) or [`Grundformverweis Konj`](https://de.wiktionary.org/wiki/Vorlage:Grundformverweis_Konj).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_of: Option<FormOf<'a>>,
+
+    /// Grammatical genders in order, from the gender templates in the heading of the POS entry.
+    ///
+    /// When the heading has no gender templates, the genders are taken from the parameter `Genus` of the noun overview template if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genders: Vec<Gender>,
 
     /// Hypernyms, from the section [`Oberbegriffe`](https://de.wiktionary.org/wiki/Vorlage:Oberbegriffe).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub hypernyms: Vec<Vec<Flowing<'a>>>,
+    pub hypernyms: Vec<ListItem<'a>>,
 
-    /// Hyphenation, from the section [`Worttrennung`](https://de.wiktionary.org/wiki/Vorlage:Worttrennung).
+    /// Hyphenation of the lemma and other forms, from the section [`Worttrennung`](https://de.wiktionary.org/wiki/Vorlage:Worttrennung), with one list of hyphenations for each line of the section.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub hyphenation: Vec<Vec<Flowing<'a>>>,
+    pub hyphenation: Vec<Vec<Hyphenation<'a>>>,
 
     /// Hyponyms, from the section [`Unterbegriffe`](https://de.wiktionary.org/wiki/Vorlage:Unterbegriffe).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub hyponyms: Vec<Vec<Flowing<'a>>>,
+    pub hyponyms: Vec<ListItem<'a>>,
 
     /// Idioms, from the section [`Redewendungen`](https://de.wiktionary.org/wiki/Vorlage:Redewendungen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub idioms: Vec<Vec<Flowing<'a>>>,
+    pub idioms: Vec<ListItem<'a>>,
 
-    /// IPA, from the subsection [`IPA`](https://de.wiktionary.org/wiki/Vorlage:IPA) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
+    /// Pronunciations in IPA of the lemma and other forms, from the subsection [`IPA`](https://de.wiktionary.org/wiki/Vorlage:IPA) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub ipa: Vec<Flowing<'a>>,
+    pub ipa: Vec<Pronunciation<'a>>,
 
     /// Masculine forms, from the section [`Männliche Wortformen`](https://de.wiktionary.org/wiki/Vorlage:M%C3%A4nnliche_Wortformen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub masculine_forms: Vec<Vec<Flowing<'a>>>,
+    pub masculine_forms: Vec<ListItem<'a>>,
 
     /// No longer valid spellings, from the section [`Nicht mehr gültige Schreibweisen`](https://de.wiktionary.org/wiki/Vorlage:Nicht_mehr_g%C3%BCltige_Schreibweisen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub no_longer_valid_spellings: Vec<Vec<Flowing<'a>>>,
+    pub no_longer_valid_spellings: Vec<ListItem<'a>>,
+
+    /// Declension of a German noun, from the overview template [`Deutsch Substantiv Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Substantiv_%C3%9Cbersicht).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub noun_inflection: Option<NounInflection<'a>>,
 
     /// Various information about the entry, from any of the many overview templates.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub overview: Option<Overview<'a>>,
 
+    /// All parts of speech of the entry in order, from the templates [`Wortart`](https://de.wiktionary.org/wiki/Vorlage:Wortart) in the heading of the POS entry.
+    ///
+    /// The first of these is the same as [`pos`](#structfield.pos).
+    pub parts_of_speech: Vec<Pos>,
+
     /// The first part of speech of the entry, from the heading of the POS entry.
     pub pos: Pos,
 
     /// Proverbs, fro mthe section [`Sprichwörter`](https://de.wiktionary.org/wiki/Vorlage:Sprichw%C3%B6rter).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub proverbs: Vec<Vec<Flowing<'a>>>,
+    pub proverbs: Vec<ListItem<'a>>,
 
     /// Related words, from the section [`Sinnverwandte Wörter`](https://de.wiktionary.org/wiki/Vorlage:Sinnverwandte_W%C3%B6rter).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub related_words: Vec<Vec<Flowing<'a>>>,
+    pub related_words: Vec<ListItem<'a>>,
 
     /// Rhymes, from the subsection [`Reime`](https://de.wiktionary.org/wiki/Vorlage:Reime) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -525,27 +1115,258 @@ pub struct PosEntry<'a> {
 
     /// Short forms, from the section [`Kurzformen`](https://de.wiktionary.org/wiki/Vorlage:Kurzformen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub short_forms: Vec<Vec<Flowing<'a>>>,
+    pub short_forms: Vec<ListItem<'a>>,
 
     /// Similar words, from the section [`Ähnlichkeiten`](https://de.wiktionary.org/wiki/Vorlage:%C3%84hnlichkeiten).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub similar_words: Vec<Vec<Flowing<'a>>>,
+    pub similar_words: Vec<ListItem<'a>>,
 
     /// Symbols, from the section [`Symbole`](https://de.wiktionary.org/wiki/Vorlage:Symbole).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub symbols: Vec<Vec<Flowing<'a>>>,
+    pub symbols: Vec<ListItem<'a>>,
 
     /// Synonyms, from the section [`Synonyme`](https://de.wiktionary.org/wiki/Vorlage:Synonyme).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub synonyms: Vec<Vec<Flowing<'a>>>,
+    pub synonyms: Vec<ListItem<'a>>,
+
+    /// Translations by language, from the template [`Ü-Tabelle`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C-Tabelle) in the section [`Übersetzungen`](https://de.wiktionary.org/wiki/Vorlage:%C3%9Cbersetzungen).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translations: Vec<Translation<'a>>,
 
     /// Typical word combinations, from the section [`Charakteristische Wortkombinationen`](https://de.wiktionary.org/wiki/Vorlage:Charakteristische_Wortkombinationen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub typical_word_combinations: Vec<Vec<Flowing<'a>>>,
+    pub typical_word_combinations: Vec<ListItem<'a>>,
 
     /// Variants, from the section [`Nebenformen`](https://de.wiktionary.org/wiki/Vorlage:Nebenformen).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub variants: Vec<Vec<Flowing<'a>>>,
+    pub variants: Vec<ListItem<'a>>,
+
+    /// Principal forms of a German verb, from the overview template [`Deutsch Verb Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Verb_%C3%9Cbersicht).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb_overview: Option<VerbOverview<'a>>,
+
+    /// Word formations grouped by part of speech or label, from the section [`Wortbildungen`](https://de.wiktionary.org/wiki/Vorlage:Wortbildungen).
+    ///
+    /// This is the same information as in [`compound_words`](#structfield.compound_words), split into groups at each label, such as `Adjektive:` or `{{Wortbildung|Adj}}`, with the link targets in each group. Text other than the labels and links is left out.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub word_formations: Vec<WordFormationGroup<'a>>,
+}
+
+/// Pronunciation in IPA of a form of the word.
+///
+/// Parsed from the subsection [`IPA`](https://de.wiktionary.org/wiki/Vorlage:IPA) in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Pronunciation<'a> {
+    /// The form pronounced, from the template such as [`Pl.`](https://de.wiktionary.org/wiki/Vorlage:Pl.) preceding the pronunciation, or `None` for the lemma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form: Option<WordForm>,
+
+    /// The pronunciation written in IPA, from the template [`Lautschrift`](https://de.wiktionary.org/wiki/Vorlage:Lautschrift).
+    pub ipa: Cow<'a, str>,
+
+    /// Labels such as the region where the pronunciation is used, from text such as `österr.:` or a label template preceding the pronunciation, or text in parentheses following it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<LabelReference<'a>>,
+}
+
+/// Relation to a linked term, from an item in a list of related terms, such as synonyms or antonyms.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Relation<'a> {
+    /// The labels qualifying the relation.
+    ///
+    /// Labels given as templates or as italic text apply to the term following them up to the next comma or semicolon, or up to the next semicolon if followed by a colon, as in `{{ugs.}}: [[Bank]], [[Kasse]]`. Labels in parentheses apply to the term preceding them, as in `[[Geldhaus]] ''(umgangssprachlich)''`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<LabelReference<'a>>,
+
+    /// The senses the relation applies to, from the reference in brackets before the term, or at the start of the list item if there is none before the term.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senses: Vec<Sense>,
+
+    /// The title of the page of the term, from the target of the link.
+    pub target: Cow<'a, str>,
+}
+
+/// Index from rhymes to the words that rhyme with them, built from parsed pages.
+///
+/// Entries are added from the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache) of dictionary pages with [`add_output`](#method.add_output) and from pages in the namespace `Reim` with [`add_rhyme_page`](#method.add_rhyme_page). Each entry records which of the two sources it was found in, so that words found in only one of them can be found by comparing the sources.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RhymeIndex {
+    /// The entries by rhyme, written in IPA without the leading hyphen.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rhymes: BTreeMap<String, Vec<RhymeIndexEntry>>,
+}
+
+/// Entry in a [`RhymeIndex`](struct.RhymeIndex.html).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RhymeIndexEntry {
+    /// Whether the rhyme is given in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache) of the dictionary page of the word.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub in_entry: bool,
+
+    /// Whether the word is listed on the page of the rhyme in the namespace `Reim`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub in_rhyme_page: bool,
+
+    /// The language of the word.
+    pub language: Language,
+
+    /// The word, which is the title of its dictionary page.
+    pub lemma: String,
+
+    /// The part of speech of the word, or `None` if the word is only known from a page in the namespace `Reim`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<Pos>,
+
+    /// The number of syllables of the word, from the hyphenation of the lemma in the section [`Worttrennung`](https://de.wiktionary.org/wiki/Vorlage:Worttrennung), or otherwise from the heading it's listed under on the page of the rhyme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syllables: Option<usize>,
+}
+
+/// Output of parsing a page in the namespace `Reim`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RhymeOutput<'a> {
+    /// The language of the rhyme, from the title of the page, or `None` if the language is not recognized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The rhyme written in IPA, from the title of the page without the leading hyphen.
+    pub rhyme: String,
+
+    /// Warnings from the parser telling that something is not well-formed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+
+    /// The words listed on the page, in the order they occur.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<RhymeWord<'a>>,
+}
+
+/// Word listed on a page in the namespace `Reim`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RhymeWord<'a> {
+    /// The number of syllables of the word, from the heading the word is listed under, if the heading gives it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syllables: Option<usize>,
+
+    /// The word, from the target of the link to its dictionary page, without any section given after `#`.
+    pub word: Cow<'a, str>,
+}
+
+/// Principal forms of a German verb.
+///
+/// Parsed from the template [`Deutsch Verb Übersicht`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Verb_%C3%9Cbersicht). Each field contains the form given by the parameter named in its documentation, followed by any variants given by the same parameter name followed by `*` or a number, marked the same way as in [`NounInflection`](struct.NounInflection.html). Forms that are given as a dash are left out.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VerbOverview<'a> {
+    /// Auxiliary verbs used to form the perfect tenses, from the parameter `Hilfsverb`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auxiliaries: Vec<Auxiliary>,
+
+    /// Imperative plural, from the parameter `Imperativ Plural`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imperative_plural: Vec<OverviewForm<'a>>,
+
+    /// Imperative singular, from the parameter `Imperativ Singular`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imperative_singular: Vec<OverviewForm<'a>>,
+
+    /// Past participle, from the parameter `Partizip II`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub past_participle: Vec<OverviewForm<'a>>,
+
+    /// Present tense, first person singular, from the parameter `Präsens_ich`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub present_first_singular: Vec<OverviewForm<'a>>,
+
+    /// Present tense, second person singular, from the parameter `Präsens_du`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub present_second_singular: Vec<OverviewForm<'a>>,
+
+    /// Present tense, third person singular, from the parameter `Präsens_er, sie, es`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub present_third_singular: Vec<OverviewForm<'a>>,
+
+    /// Preterite, first person singular, from the parameter `Präteritum_ich`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preterite_first_singular: Vec<OverviewForm<'a>>,
+
+    /// Subjunctive II, first person singular, from the parameter `Konjunktiv II_ich`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjunctive_ii_first_singular: Vec<OverviewForm<'a>>,
+}
+
+/// Reference to a sense of the entry.
+///
+/// Parsed from the number and optional letter written between brackets, such as `[1]` or `[2a]`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Sense {
+    /// The letter of the sub-sense, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub letter: Option<char>,
+
+    /// The number of the sense.
+    pub number: u32,
+}
+
+/// Tense of a verb form.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tense {
+    /// Future (“Futur I”)
+    Future,
+
+    /// Future perfect (“Futur II”)
+    FuturePerfect,
+
+    /// Perfect (“Perfekt”)
+    Perfect,
+
+    /// Pluperfect (“Plusquamperfekt”)
+    Pluperfect,
+
+    /// Present (“Präsens”)
+    Present,
+
+    /// Preterite (“Präteritum”)
+    Preterite,
+}
+
+/// Translations into a single language.
+///
+/// Parsed from a line in the template [`Ü-Tabelle`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C-Tabelle).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Translation<'a> {
+    /// The translations grouped by the senses they translate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<TranslationItem<'a>>,
+
+    /// The language translated into, from the language template at the start of the line.
+    pub language: LanguageReference<'a>,
+}
+
+/// Group of translations following a sense reference.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TranslationItem<'a> {
+    /// The senses the translations refer to, from the reference in brackets preceding them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senses: Vec<Sense>,
+
+    /// The translations, typically parsed from the templates [`Ü`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C) and [`Üt`](https://de.wiktionary.org/wiki/Vorlage:%C3%9Ct), together with any text and other elements between them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub terms: Vec<Flowing<'a>>,
+}
+
+/// Voice of a verb form.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Voice {
+    /// Active voice (“Aktiv”)
+    Active,
+
+    /// Process passive (“Vorgangspassiv”), formed with `werden`
+    ProcessPassive,
+
+    /// State passive (“Zustandspassiv”), formed with `sein`
+    StatePassive,
 }
 
 /// Warning from the parser telling that something is not well-formed.
@@ -579,6 +1400,16 @@ pub enum WarningMessage {
     /// The element is missing some required content.
     Empty,
 
+    /// A form not given by the element would have to be derived by a rule that can't tell all cases apart.
+    ///
+    /// This is the case for the past participle of a regular verb whose stem starts with what may be an inseparable prefix, such as `bestell`, and for the genitive singular of a strong noun of one syllable, such as `Tag`, which may end in either `s` or `es`. The form and the forms built from it are left out of the output.
+    FormAmbiguous,
+
+    /// The value of the element is a pronunciation written in IPA, but it contains characters that are not valid in IPA.
+    ///
+    /// A common cause is `:` or `'` written instead of `ː` or `ˈ`. The value is included in the output as written.
+    IpaInvalid,
+
     /// The section following the heading is missing some required content.
     SectionEmpty,
 
@@ -586,7 +1417,7 @@ pub enum WarningMessage {
     ///
     /// The element conveys meaningful information, but this information has not been parsed and is not represented in the output. In contrast to other warnings, this warning does not indicate there is anything wrong with the wiki text. It just indicates that the wiki text contains additional information that is not represented in the output. The element is recognized as valid in the position it occurs, but its content is not parsed, and nothing can be said about whether the content is valid.
     ///
-    /// This applies for example to the section [`Referenzen`](https://de.wiktionary.org/wiki/Vorlage:Referenzen), the templates [`Ü-Tabelle`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C-Tabelle) and [`erweitern`](https://de.wiktionary.org/wiki/Vorlage:erweitern) and the extension tag `ref`.
+    /// This applies for example to the section [`Referenzen`](https://de.wiktionary.org/wiki/Vorlage:Referenzen), the template [`erweitern`](https://de.wiktionary.org/wiki/Vorlage:erweitern), the parameter `Dialekttabelle` of the template [`Ü-Tabelle`](https://de.wiktionary.org/wiki/Vorlage:%C3%9C-Tabelle) and the extension tag `ref`.
     Supplementary,
 
     /// The element is not recognized.
@@ -609,6 +1440,64 @@ pub enum WarningMessage {
     ValueUnrecognized,
 }
 
+/// Grammatical form of a word other than the lemma, as marked in the sections [`Worttrennung`](https://de.wiktionary.org/wiki/Vorlage:Worttrennung) and [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordForm {
+    /// Comparative, from the template [`Komp.`](https://de.wiktionary.org/wiki/Vorlage:Komp.)
+    Comparative,
+
+    /// Genitive, from the template [`Gen.`](https://de.wiktionary.org/wiki/Vorlage:Gen.)
+    Genitive,
+
+    /// Past participle, from the template [`Part.`](https://de.wiktionary.org/wiki/Vorlage:Part.)
+    PastParticiple,
+
+    /// Plural, from the template [`Pl.`](https://de.wiktionary.org/wiki/Vorlage:Pl.)
+    Plural,
+
+    /// First of several plurals, from the template [`Pl.1`](https://de.wiktionary.org/wiki/Vorlage:Pl.1)
+    Plural1,
+
+    /// Second of several plurals, from the template [`Pl.2`](https://de.wiktionary.org/wiki/Vorlage:Pl.2)
+    Plural2,
+
+    /// Third of several plurals, from the template [`Pl.3`](https://de.wiktionary.org/wiki/Vorlage:Pl.3)
+    Plural3,
+
+    /// Fourth of several plurals, from the template [`Pl.4`](https://de.wiktionary.org/wiki/Vorlage:Pl.4)
+    Plural4,
+
+    /// Preterite, from the template [`Prät.`](https://de.wiktionary.org/wiki/Vorlage:Pr%C3%A4t.)
+    Preterite,
+
+    /// Singular, only given as missing, from the template [`kSg.`](https://de.wiktionary.org/wiki/Vorlage:kSg.)
+    Singular,
+
+    /// Superlative, from the template [`Sup.`](https://de.wiktionary.org/wiki/Vorlage:Sup.)
+    Superlative,
+}
+
+/// Group of word formations sharing a part of speech or label.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WordFormationGroup<'a> {
+    /// The label of the group, if it's not the name of a part of speech, such as “Konversionen”.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<Cow<'a, str>>,
+
+    /// The lemmas in the group, from the targets of the links.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lemmas: Vec<Cow<'a, str>>,
+
+    /// The part of speech of the lemmas in the group, from the template [`Wortbildung`](https://de.wiktionary.org/wiki/Vorlage:Wortbildung) or the label of the group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos: Option<Pos>,
+
+    /// The senses the group refers to, from the reference in brackets at the start of the list item.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senses: Vec<Sense>,
+}
+
 /// Parses an article from the German language version of Wiktionary into structured data.
 ///
 /// `title` is the title of the article. `wiki_text` is the wiki text of the article. `nodes` is the sequence of nodes obtained by parsing the wiki text with the crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text).
@@ -679,3 +1568,47 @@ pub fn parse<'a>(title: &str, wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<
         warnings: context.warnings,
     }
 }
+
+/// Parses a page in the namespace `Reim` from the German language version of Wiktionary into the words listed on it.
+///
+/// `title` is the title of the page, such as `Reim:Deutsch:-aʊ̯s`. `wiki_text` is the wiki text of the page. `nodes` is the sequence of nodes obtained by parsing the wiki text with the crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text).
+///
+/// The words are taken from the links in the lists on the page. Headings that give a number of syllables, such as `2 Silben` or `Zweisilbige Reime`, apply to the words following them.
+#[must_use]
+pub fn parse_rhyme_page<'a>(title: &str, wiki_text: &'a str, nodes: &[Node<'a>]) -> RhymeOutput<'a> {
+    let mut context = Context {
+        language: None,
+        warnings: vec![],
+        wiki_text,
+    };
+    let title = title.trim_start_matches("Reim:");
+    let (language, rhyme) = match title.find(':') {
+        None => (None, title),
+        Some(index) => (Language::from_name(&title[..index]), &title[index + 1..]),
+    };
+    RhymeOutput {
+        language,
+        rhyme: rhyme.trim_start_matches('-').to_string(),
+        words: rhyme_page::parse_rhyme_page(&mut context, nodes),
+        warnings: context.warnings,
+    }
+}
+
+/// Parses a page in the namespace `Flexion` from the German language version of Wiktionary into tables of inflected forms.
+///
+/// `title` is the title of the page, with or without the prefix `Flexion:`. `wiki_text` is the wiki text of the page. `nodes` is the sequence of nodes obtained by parsing the wiki text with the crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text).
+///
+/// The templates [`Deutsch Verb regelmäßig`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Verb_regelm%C3%A4%C3%9Fig) and [`Deutsch Verb unregelmäßig`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Verb_unregelm%C3%A4%C3%9Fig) are parsed into all persons, tenses, moods and voices of the verb, [`Deutsch Adjektiv regelmäßig`](https://de.wiktionary.org/wiki/Vorlage:Deutsch_Adjektiv_regelm%C3%A4%C3%9Fig) into the strong, weak and mixed declension of each degree of the adjective, and the templates `Deutsch Substantiv` followed by the gender and `stark` or `schwach` into the cases and numbers of the noun. Other templates cause a warning.
+#[must_use]
+pub fn parse_flexion<'a>(title: &str, wiki_text: &'a str, nodes: &[Node<'a>]) -> FlexionOutput<'a> {
+    let mut context = Context {
+        language: None,
+        warnings: vec![],
+        wiki_text,
+    };
+    let lemma = title.trim_start_matches("Flexion:");
+    FlexionOutput {
+        paradigms: flexion::parse_flexion(&mut context, lemma, nodes),
+        warnings: context.warnings,
+    }
+}
@@ -7,7 +7,29 @@ pub fn parse_list<'a>(
     template_node: &::Node,
     parameters: &[::Parameter],
     nodes: &[::Node<'a>],
-    output: &mut Option<Vec<Vec<::Flowing<'a>>>>,
+    output: &mut Option<Vec<::ListItem<'a>>>,
+) -> usize {
+    parse_list_with_relations(context, template_node, parameters, nodes, output, false)
+}
+
+// For the sections giving lexical relations to other words, such as synonyms, also splits each list item into relations.
+pub fn parse_relation_list<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter],
+    nodes: &[::Node<'a>],
+    output: &mut Option<Vec<::ListItem<'a>>>,
+) -> usize {
+    parse_list_with_relations(context, template_node, parameters, nodes, output, true)
+}
+
+fn parse_list_with_relations<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter],
+    nodes: &[::Node<'a>],
+    output: &mut Option<Vec<::ListItem<'a>>>,
+    relations: bool,
 ) -> usize {
     ::parse_list_items_generic(
         context,
@@ -20,24 +42,156 @@ pub fn parse_list<'a>(
                 ::add_warning(context, list_item, ::WarningMessage::Empty);
                 None
             } else {
-                Some(parse_list_item(context, &list_item.nodes, true))
+                let mut content = parse_list_item(context, &list_item.nodes, true);
+                let senses = ::senses::parse_optional_senses(context, list_item, &mut content);
+                let relations = if relations {
+                    parse_relations(&content, &senses)
+                } else {
+                    vec![]
+                };
+                Some(::ListItem {
+                    content,
+                    relations,
+                    senses,
+                })
             }
         },
     )
 }
 
+// Creates one relation for each link, with the senses given before it in the list item and the labels given before it up to the previous separator, as well as any labels given in parentheses after it. A sense reference in the middle of the list item, as in `[1] [[Geldinstitut]]; [2] [[Sitzbank]]`, applies to the links following it.
+fn parse_relations<'a>(content: &[::Flowing<'a>], senses: &[::Sense]) -> Vec<::Relation<'a>> {
+    let mut state = RelationState {
+        italic: false,
+        labels: vec![],
+        parenthesized: false,
+        relations: vec![],
+        senses: senses.to_vec(),
+        sticky: false,
+    };
+    state.parse_flowing(content);
+    state.relations
+}
+
+struct RelationState<'a> {
+    italic: bool,
+    labels: Vec<::LabelReference<'a>>,
+
+    // Whether in parentheses, where labels apply to the relation preceding them, as in `[[Geldhaus]] ''(umgangssprachlich)''`.
+    parenthesized: bool,
+
+    relations: Vec<::Relation<'a>>,
+    senses: Vec<::Sense>,
+
+    // Whether the labels were followed by a colon, making them apply up to the next semicolon rather than the next comma.
+    sticky: bool,
+}
+
+impl<'a> RelationState<'a> {
+    fn parse_flowing(&mut self, flowing: &[::Flowing<'a>]) {
+        for item in flowing {
+            match item {
+                ::Flowing::Context { labels } => match self.relations.last_mut() {
+                    Some(relation) if self.parenthesized => {
+                        relation.labels.extend(labels.iter().cloned())
+                    }
+                    _ => self.labels.extend(labels.iter().cloned()),
+                },
+                ::Flowing::Italic => self.italic = !self.italic,
+                ::Flowing::Link { target, .. } => self.relations.push(::Relation {
+                    labels: self.labels.clone(),
+                    senses: self.senses.clone(),
+                    target: target.clone(),
+                }),
+                ::Flowing::List { items } => {
+                    for item in items {
+                        self.parse_flowing(item);
+                    }
+                }
+                ::Flowing::Text { value } => {
+                    let mut position = 0;
+                    while let Some((start, end, senses)) = ::senses::find_senses(&value[position..])
+                    {
+                        self.parse_text(&value[position..position + start]);
+                        self.labels.clear();
+                        self.senses = senses;
+                        self.sticky = false;
+                        position += end;
+                    }
+                    self.parse_text(&value[position..]);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_text(&mut self, mut text: &str) {
+        while let Some(index) = text.find(['(', ')']) {
+            self.parse_segment(&text[..index]);
+            self.parenthesized = text[index..].starts_with('(');
+            text = &text[index + 1..];
+        }
+        self.parse_segment(text);
+    }
+
+    // Text in parentheses that is neither in italics nor a known label, as in `[[Kasse]] (auch [[Geldkasse]])`, is not taken to be labels.
+    fn parse_segment(&mut self, text: &str) {
+        if self.parenthesized {
+            let italic = self.italic;
+            if let Some(relation) = self.relations.last_mut() {
+                relation.labels.extend(
+                    parse_labels(text, &[',', ':', ';'])
+                        .into_iter()
+                        .filter(|label| italic || matches!(label, ::LabelReference::Known(_))),
+                );
+            }
+            return;
+        }
+        if self.italic {
+            self.labels.extend(parse_labels(text, &[',', ':']));
+        }
+        if text.contains(';') {
+            self.labels.clear();
+            self.sticky = false;
+        } else if text.trim_start().starts_with(':') || self.italic && text.contains(':') {
+            self.sticky = true;
+        } else if text.contains(',') && !self.sticky {
+            self.labels.clear();
+        }
+    }
+}
+
+// Labels not known to the parser are kept as written, the same as in the `K` template. Function words, as in `selten auch`, are not part of the labels.
+fn parse_labels<'a>(text: &str, separators: &[char]) -> Vec<::LabelReference<'a>> {
+    text.split(separators)
+        .map(|label| {
+            label
+                .split_whitespace()
+                .filter(|word| !["auch", "bzw.", "oder", "und"].contains(word))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|label| !label.is_empty())
+        .map(|label| ::LabelReference::from_name(::Cow::Owned(label)))
+        .collect()
+}
+
 macro_rules! parse_list_item {
     {
         language_adjectives { $( $language_adjective:tt ),+ }
         languages { $( $language:tt ),+ }
         simple $( ( $simple_name:tt, $simple_variant:tt ) )+
     } => {
-        fn parse_list_item<'a>(
+        // Names of the templates recognized in list items as giving a language by its code, such as `en`.
+        #[cfg(test)]
+        const LANGUAGE_TEMPLATES: &[&str] = &[$( $language ),+];
+
+        pub fn parse_list_item<'a, 'b>(
             context: &mut ::Context<'a>,
-            nodes: &[::Node<'a>],
+            nodes: impl IntoIterator<Item = &'b ::Node<'a>>,
             allow_list: bool
-        ) -> Vec<::Flowing<'a>> {
-            nodes.iter().filter_map(|node| match node {
+        ) -> Vec<::Flowing<'a>> where 'a: 'b {
+            nodes.into_iter().filter_map(|node| match node {
                 ::Node::Bold { .. } => Some(::Flowing::Bold),
                 ::Node::CharacterEntity { character: '\u{a0}', .. } => Some(::Flowing::Text {
                     value: ::Cow::Borrowed("\u{a0}")
@@ -61,17 +215,23 @@ macro_rules! parse_list_item {
                             ::add_warning(context, node, ::WarningMessage::Supplementary);
                             ::Flowing::QualityControl
                         }
+                        "K" => parse_context(context, node, parameters),
                         "Wortbildung" => parse_pos(context, node, parameters),
                         "Ü" => parse_term(context, node, parameters),
                         "Üt" => parse_term_transliteration(context, node, parameters),
                         $( concat!($language_adjective, ".") => ::parse_simple_template(context, node, parameters, ::Flowing::LanguageAdjective {
-                            language: ::Cow::Borrowed($language_adjective)
+                            language: parse_language_adjective($language_adjective)
                         }), )+
                         $( $language => ::parse_simple_template(context, node, parameters, ::Flowing::Language {
-                            language: ::Cow::Borrowed($language)
+                            language: ::LanguageReference::from_code(::Cow::Borrowed($language))
                         }), )+
                         $( $simple_name => ::parse_simple_template(context, node, parameters, ::Flowing::$simple_variant), )+
-                        _ => ::create_unknown(context, node, ::WarningMessage::Unrecognized)
+                        _ => match ::Label::from_abbreviation(&name) {
+                            None => ::create_unknown(context, node, ::WarningMessage::Unrecognized),
+                            Some(label) => ::parse_simple_template(context, node, parameters, ::Flowing::Context {
+                                labels: vec![::LabelReference::Known(label)]
+                            })
+                        }
                     }
                 }),
                 ::Node::Text { value, .. } => Some(::Flowing::Text { value: ::Cow::Borrowed(value) }),
@@ -99,10 +259,8 @@ macro_rules! parse_list_item {
 parse_list_item! {
     language_adjectives {
         "ahd",
-        "amer",
         "ang",
         "ar",
-        "brit",
         "cy",
         "da",
         "de",
@@ -136,20 +294,16 @@ parse_list_item! {
         "nl",
         "no",
         "non",
-        "nordd",
         "ofs",
         "osx",
         "owen",
         "pt",
         "ru",
-        "schweiz",
         "spätlat",
         "sv",
-        "süddt",
         "th",
         "tr",
-        "wen",
-        "österr"
+        "wen"
     }
     languages {
         "KA",
@@ -684,6 +838,7 @@ parse_list_item! {
         "zza"
     }
     simple
+        ("Gen.", Genitive)
         ("Komp.", Comparative)
         ("Part.", PastParticiple)
         ("Pl.", Plural)
@@ -693,7 +848,79 @@ parse_list_item! {
         ("Pl.4", Plural4)
         ("Prät.", Preterite)
         ("Sup.", Superlative)
+        ("f", FeminineGender)
         ("kPl.", NoPlural)
+        ("kSg.", NoSingular)
+        ("m", MasculineGender)
+        ("n", NeuterGender)
+        ("u", CommonGender)
+}
+
+fn parse_context<'a>(
+    context: &mut ::Context<'a>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+) -> ::Flowing<'a> {
+    let mut labels = vec![];
+    let mut text = None;
+    for parameter in parameters {
+        match &parameter.name {
+            None => match ::parse_text_not_empty(&parameter.value) {
+                None => {
+                    return ::create_unknown2(
+                        context,
+                        template_node,
+                        parameter,
+                        ::WarningMessage::ValueUnrecognized,
+                    )
+                }
+                Some(label) => labels.push(::LabelReference::from_name(label)),
+            },
+            Some(_) => match ::parse_parameter_name(parameter) {
+                Some("ft") => {
+                    if text.is_some() {
+                        return ::create_unknown2(
+                            context,
+                            template_node,
+                            parameter,
+                            ::WarningMessage::Duplicate,
+                        );
+                    }
+                    text = ::parse_text_not_empty(&parameter.value);
+                }
+                Some("spr") => {}
+                // The parameters `t1`, `t2` and so on give the separator following each label.
+                Some(name)
+                    if name.starts_with('t')
+                        && name.len() > 1
+                        && name[1..].bytes().all(|byte| byte.is_ascii_digit()) => {}
+                _ => {
+                    return ::create_unknown2(
+                        context,
+                        template_node,
+                        parameter,
+                        ::WarningMessage::Unrecognized,
+                    )
+                }
+            },
+        }
+    }
+    labels.extend(text.map(::LabelReference::Unknown));
+    if labels.is_empty() {
+        return ::create_unknown(context, template_node, ::WarningMessage::Empty);
+    }
+    ::Flowing::Context { labels }
+}
+
+fn parse_language_adjective(name: &'static str) -> ::LanguageReference<'static> {
+    // Some of the templates are named after an abbreviation of the German adjective rather than the code of the language. The remaining ones, such as `frühnhd` and `spätlat`, refer to a stage of a language not known to the parser.
+    ::LanguageReference::from_code(::Cow::Borrowed(match name {
+        "ahd" => "goh",
+        "lat" => "la",
+        "mhd" => "gmh",
+        "mlg" => "gml",
+        _ => name,
+    }))
 }
 
 fn parse_pos<'a>(
@@ -703,13 +930,7 @@ fn parse_pos<'a>(
 ) -> ::Flowing<'a> {
     if let [parameter @ ::Parameter { name: None, .. }] = parameters {
         if let Some(text) = ::parse_text(&parameter.value) {
-            if let Some(pos) = match &text as _ {
-                "Adj" | "Adje" | "Adjektiv" | "Adjektive" => Some(::Pos::Adjective),
-                "Adv" | "Adve" | "Adverb" | "Adverbien" => Some(::Pos::Adverb),
-                "Sub" | "Subs" | "Substantiv" | "Substantive" => Some(::Pos::Noun),
-                "Ver" | "Verb" | "Verben" => Some(::Pos::Verb),
-                _ => None,
-            } {
+            if let Some(pos) = parse_word_formation_pos(&text) {
                 return ::Flowing::Pos { pos };
             }
         }
@@ -717,6 +938,17 @@ fn parse_pos<'a>(
     ::create_unknown(context, template_node, ::WarningMessage::ValueUnrecognized)
 }
 
+// Parses the name of a part of speech as given to the template `Wortbildung` or written out as the label of a group of word formations.
+pub fn parse_word_formation_pos(name: &str) -> Option<::Pos> {
+    Some(match name {
+        "Adj" | "Adje" | "Adjektiv" | "Adjektive" => ::Pos::Adjective,
+        "Adv" | "Adve" | "Adverb" | "Adverbien" => ::Pos::Adverb,
+        "Sub" | "Subs" | "Substantiv" | "Substantive" => ::Pos::Noun,
+        "Ver" | "Verb" | "Verben" => ::Pos::Verb,
+        _ => return None,
+    })
+}
+
 fn parse_term<'a>(
     context: &mut ::Context<'a>,
     template_node: &::Node,
@@ -740,7 +972,7 @@ fn parse_term<'a>(
                     ::WarningMessage::ValueUnrecognized,
                 ),
                 Some(term) => ::Flowing::Term {
-                    language,
+                    language: ::LanguageReference::from_code(language),
                     term,
                     transliteration: None,
                 },
@@ -781,7 +1013,7 @@ fn parse_term_transliteration<'a>(
                         ::WarningMessage::ValueUnrecognized,
                     ),
                     transliteration @ Some(_) => ::Flowing::Term {
-                        language,
+                        language: ::LanguageReference::from_code(language),
                         term,
                         transliteration,
                     },
@@ -792,3 +1024,16 @@ fn parse_term_transliteration<'a>(
         ::create_unknown(context, template_node, ::WarningMessage::ValueUnrecognized)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    // Every language template resolves to a language, except the few whose language isn't documented.
+    #[test]
+    fn language_templates() {
+        for code in super::LANGUAGE_TEMPLATES {
+            if !["KA", "MHA", "qka", "qts"].contains(code) {
+                assert!(::Language::from_code(code).is_some(), "{}", code);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_noun_inflection<'a>(
+    context: &mut ::Context<'a>,
+    parameters: &[::Parameter<'a>],
+) -> ::NounInflection<'a> {
+    let mut genders = vec![];
+    let mut inflection = ::NounInflection::default();
+    for parameter in parameters {
+        let (name, variant, alternative) = match ::overview::parse_parameter(context, parameter) {
+            None => continue,
+            Some(parsed) => parsed,
+        };
+        let forms = match name {
+            "Bild" => continue,
+            "Genus" => {
+                if let Some(value) = ::parse_text_not_empty(&parameter.value) {
+                    match &value as _ {
+                        // Nouns that only exist in plural have the genus `0`.
+                        "0" => {}
+                        "f" => genders.push((variant, ::Gender::Feminine)),
+                        "m" => genders.push((variant, ::Gender::Masculine)),
+                        "n" => genders.push((variant, ::Gender::Neuter)),
+                        "u" => genders.push((variant, ::Gender::Common)),
+                        _ => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+                    }
+                }
+                continue;
+            }
+            "Akkusativ Plural" => &mut inflection.accusative_plural,
+            "Akkusativ Singular" => &mut inflection.accusative_singular,
+            "Dativ Plural" => &mut inflection.dative_plural,
+            "Dativ Singular" => &mut inflection.dative_singular,
+            "Genitiv Plural" => &mut inflection.genitive_plural,
+            "Genitiv Singular" => &mut inflection.genitive_singular,
+            "Nominativ Plural" => &mut inflection.nominative_plural,
+            "Nominativ Singular" => &mut inflection.nominative_singular,
+            _ => {
+                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                continue;
+            }
+        };
+        if let Some(form) = ::overview::parse_form(parameter) {
+            forms.push(::OverviewForm {
+                alternative,
+                form,
+                variant,
+            });
+        }
+    }
+    // The parameters are not necessarily given in the order of their numbers.
+    genders.sort_by_key(|(variant, _)| *variant);
+    inflection.genders = genders.into_iter().map(|(_, gender)| gender).collect();
+    inflection
+}
@@ -2,17 +2,25 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
+#[derive(Default)]
+pub struct Overview<'a> {
+    pub comparison: Option<::Comparison<'a>>,
+    pub noun_inflection: Option<::NounInflection<'a>>,
+    pub overview: Option<::Overview<'a>>,
+    pub verb_overview: Option<::VerbOverview<'a>>,
+}
+
 pub fn parse_overview<'a>(
     context: &mut ::Context<'a>,
     template_node: &::Node,
     name: ::Cow<'a, str>,
     parameters: &[::Parameter<'a>],
-    output: &mut Option<Option<::Overview<'a>>>,
+    output: &mut Option<Overview<'a>>,
 ) -> bool {
     match (context.language.unwrap(), &name as _) {
-        (::Language::De, "Bairisch Substantiv Übersicht m")
-        | (::Language::De, "Bairisch Substantiv Übersicht n")
-        | (::Language::De, "Bairisch Verb Übersicht")
+        (::Language::Bar, "Bairisch Substantiv Übersicht m")
+        | (::Language::Bar, "Bairisch Substantiv Übersicht n")
+        | (::Language::Bar, "Bairisch Verb Übersicht")
         | (::Language::De, "Deutsch Adjektiv Übersicht")
         | (::Language::De, "Deutsch Adverb Übersicht")
         | (::Language::De, "Deutsch Eigenname Übersicht")
@@ -39,7 +47,7 @@ pub fn parse_overview<'a>(
         _ => return false,
     }
     if output.is_some() {
-        *output = Some(None);
+        *output = Some(Default::default());
         ::add_warning(context, template_node, ::WarningMessage::Duplicate);
         return true;
     }
@@ -78,10 +86,78 @@ pub fn parse_overview<'a>(
             },
         }
     }
-    *output = Some(Some(::Overview {
-        name,
-        named_parameters,
-        unnamed_parameters,
-    }));
+    let mut comparison = None;
+    let mut noun_inflection = None;
+    let mut verb_overview = None;
+    match &name as _ {
+        "Deutsch Adjektiv Übersicht" | "Englisch Adjektiv Übersicht" => {
+            comparison = Some(::comparison::parse_comparison(context, parameters))
+        }
+        "Deutsch Substantiv Übersicht" => {
+            noun_inflection = Some(::noun_inflection::parse_noun_inflection(
+                context, parameters,
+            ))
+        }
+        "Deutsch Verb Übersicht" => {
+            verb_overview = Some(::verb_overview::parse_verb_overview(context, parameters))
+        }
+        _ => {}
+    }
+    *output = Some(Overview {
+        comparison,
+        noun_inflection,
+        overview: Some(::Overview {
+            name,
+            named_parameters,
+            unnamed_parameters,
+        }),
+        verb_overview,
+    });
     true
 }
+
+// The generic parser has already warned about named parameters with a name or value that isn't text, so the parsers specific to a template skip them without warning again.
+#[must_use]
+pub fn parse_parameter<'a>(
+    context: &mut ::Context,
+    parameter: &::Parameter<'a>,
+) -> Option<(&'a str, Option<u8>, bool)> {
+    if parameter.name.is_none() {
+        ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+        return None;
+    }
+    match ::parse_parameter_name(parameter) {
+        Some(name) if ::parse_text(&parameter.value).is_some() => Some(parse_variant(name)),
+        _ => None,
+    }
+}
+
+// Splits a parameter name such as `Nominativ Plural 2` or `Genitiv Singular*` into the name of the form, the number of the variant and whether it's marked as an alternative.
+#[must_use]
+pub fn parse_variant(mut name: &str) -> (&str, Option<u8>, bool) {
+    let alternative = name.ends_with('*');
+    if alternative {
+        name = &name[..name.len() - 1];
+    }
+    let digits_length = name.len()
+        - name
+            .trim_end_matches(|character: char| character.is_ascii_digit())
+            .len();
+    if digits_length > 0 {
+        if let Ok(variant) = name[name.len() - digits_length..].parse() {
+            return (
+                name[..name.len() - digits_length].trim_end(),
+                Some(variant),
+                alternative,
+            );
+        }
+    }
+    (name, None, alternative)
+}
+
+// A dash in place of a form means the form doesn't exist.
+#[must_use]
+pub fn parse_form<'a>(parameter: &::Parameter<'a>) -> Option<::Cow<'a, str>> {
+    ::parse_text_not_empty(&parameter.value)
+        .filter(|value| !["-", "–", "—"].contains(&value.trim()))
+}
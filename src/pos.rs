@@ -0,0 +1,252 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+macro_rules! parts_of_speech {
+    ( $( $( #[$attribute:meta] )* $variant:ident: $name:tt; )+ ) => {
+        /// Part of speech.
+        #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub enum Pos {
+            $(
+                $( #[$attribute] )*
+                $variant,
+            )+
+        }
+
+        impl Pos {
+            /// All parts of speech, ordered by variant.
+            pub const ALL: &'static [Pos] = &[$( Pos::$variant ),+];
+
+            /// Returns the part of speech corresponding to the given name if any.
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Self> {
+                Some(match name {
+                    $( $name => Pos::$variant, )+
+                    _ => return None,
+                })
+            }
+
+            /// Returns the German name of the part of speech, as used in the template [`Wortart`](https://de.wiktionary.org/wiki/Vorlage:Wortart).
+            #[must_use]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $( Pos::$variant => $name ),+
+                }
+            }
+        }
+    };
+}
+
+// Each line contains the variant and the name used in the template `Wortart`.
+parts_of_speech! {
+    /// Abbreviation (“Abkürzung”)
+    Abbreviation: "Abkürzung";
+
+    /// Adjective (“Adjektiv”)
+    Adjective: "Adjektiv";
+
+    /// Adverb (“Adverb”)
+    Adverb: "Adverb";
+
+    /// Affix (“Affix”)
+    Affix: "Affix";
+
+    /// Answer particle (“Antwortpartikel”)
+    AnswerParticle: "Antwortpartikel";
+
+    /// Article (“Artikel”)
+    Article: "Artikel";
+
+    /// Auxiliary verb (“Hilfsverb”)
+    AuxiliaryVerb: "Hilfsverb";
+
+    /// Bound morpheme (“Gebundenes Lexem”)
+    BoundMorpheme: "Gebundenes Lexem";
+
+    /// Cardinal number (“Kardinalzahl”)
+    CardinalNumber: "Kardinalzahl";
+
+    /// Causal adverb (“Kausaladverb”)
+    CausalAdverb: "Kausaladverb";
+
+    /// Circumfix (“Zirkumfix”)
+    Circumfix: "Zirkumfix";
+
+    /// Circumposition (“Zirkumposition”)
+    Circumposition: "Zirkumposition";
+
+    /// Comparative (“Komparativ”)
+    Comparative: "Komparativ";
+
+    /// Comparison particle (“Vergleichspartikel”)
+    ComparisonParticle: "Vergleichspartikel";
+
+    /// Compound word (“Wortverbindung”)
+    CompoundWord: "Wortverbindung";
+
+    /// Conjugated form (“Konjugierte Form”)
+    ConjugatedForm: "Konjugierte Form";
+
+    /// Conjunction (“Konjunktion”)
+    Conjunction: "Konjunktion";
+
+    /// Conjunctional adverb (“Konjunktionaladverb”)
+    ConjunctionalAdverb: "Konjunktionaladverb";
+
+    /// Contraction (“Kontraktion”)
+    Contraction: "Kontraktion";
+
+    /// Declined form (“Deklinierte Form”)
+    DeclinedForm: "Deklinierte Form";
+
+    /// Demonstrative pronoun (“Demonstrativpronomen”)
+    DemonstrativePronoun: "Demonstrativpronomen";
+
+    /// Extended infinitive (“Erweiterter Infinitiv”)
+    ExtendedInfinitive: "Erweiterter Infinitiv";
+
+    /// First name (“Vorname”)
+    FirstName: "Vorname";
+
+    /// Focus particle (“Fokuspartikel”)
+    FocusParticle: "Fokuspartikel";
+
+    /// Greeting (“Grußformel”)
+    Greeting: "Grußformel";
+
+    /// Idiom (“Redewendung”)
+    Idiom: "Redewendung";
+
+    /// Indefinite pronoun (“Indefinitpronomen”)
+    IndefinitePronoun: "Indefinitpronomen";
+
+    /// Intensifying particle (“Gradpartikel”)
+    IntensifyingParticle: "Gradpartikel";
+
+    /// Interfix (“Interfix”)
+    Interfix: "Interfix";
+
+    /// Interjection (“Interjektion”)
+    Interjection: "Interjektion";
+
+    /// Interrogative adverb (“Interrogativadverb”)
+    InterrogativeAdverb: "Interrogativadverb";
+
+    /// Interrogative pronoun (“Interrogativpronomen”)
+    InterrogativePronoun: "Interrogativpronomen";
+
+    /// Last name (“Nachname”)
+    LastName: "Nachname";
+
+    /// Letter (“Buchstabe”)
+    Letter: "Buchstabe";
+
+    /// Local adverb (“Lokaladverb”)
+    LocalAdverb: "Lokaladverb";
+
+    /// Mnemonic (“Merkspruch”)
+    Mnemonic: "Merkspruch";
+
+    /// Modal adverb (“Modaladverb”)
+    ModalAdverb: "Modaladverb";
+
+    /// Modal particle (“Abtönungspartikel”)
+    ModalParticle: "Abtönungspartikel";
+
+    /// Negative particle (“Negationspartikel”)
+    NegativeParticle: "Negationspartikel";
+
+    /// Noun (“Substantiv”)
+    Noun: "Substantiv";
+
+    /// Numeral (“Numerale”)
+    Numeral: "Numerale";
+
+    /// Numeral classifier (“Zahlklassifikator”)
+    NumeralClassifier: "Zahlklassifikator";
+
+    /// Numeral symbol (“Zahlzeichen”)
+    NumeralSymbol: "Zahlzeichen";
+
+    /// Onomatopoeia (“Onomatopoetikum”)
+    Onomatopoeia: "Onomatopoetikum";
+
+    /// Ordinal number (“Ordinalzahl”)
+    OrdinalNumber: "Ordinalzahl";
+
+    /// Particle (“Partikel”)
+    Particle: "Partikel";
+
+    /// Past participle (“Partizip II”)
+    PastParticiple: "Partizip II";
+
+    /// Personal pronoun (“Personalpronomen”)
+    PersonalPronoun: "Personalpronomen";
+
+    /// Possessive pronoun (“Possessivpronomen”)
+    PossessivePronoun: "Possessivpronomen";
+
+    /// Postposition (“Postposition”)
+    Postposition: "Postposition";
+
+    /// Prefix (“Präfix”)
+    Prefix: "Präfix";
+
+    /// Prefixoid (“Präfixoid”)
+    Prefixoid: "Präfixoid";
+
+    /// Preposition (“Präposition”)
+    Preposition: "Präposition";
+
+    /// Present participle (“Partizip I”)
+    PresentParticiple: "Partizip I";
+
+    /// Pronominal adverb (“Pronominaladverb”)
+    PronominalAdverb: "Pronominaladverb";
+
+    /// Pronoun (“Pronomen”)
+    Pronoun: "Pronomen";
+
+    /// Proper noun (“Eigenname”)
+    ProperNoun: "Eigenname";
+
+    /// Proverb (“Sprichwort”)
+    Proverb: "Sprichwort";
+
+    /// Reciprocal pronoun (“Reziprokpronomen”)
+    ReciprocalPronoun: "Reziprokpronomen";
+
+    /// Reflexive pronoun (“Reflexivpronomen”)
+    ReflexivePronoun: "Reflexivpronomen";
+
+    /// Relative pronoun (“Relativpronomen”)
+    RelativePronoun: "Relativpronomen";
+
+    /// Subordinating conjunction (“Subjunktion”)
+    Subjunction: "Subjunktion";
+
+    /// Suffix (“Suffix”)
+    Suffix: "Suffix";
+
+    /// Suffixoid (“Suffixoid”)
+    Suffixoid: "Suffixoid";
+
+    /// Superlative (“Superlativ”)
+    Superlative: "Superlativ";
+
+    /// Symbol (“Symbol”)
+    Symbol: "Symbol";
+
+    /// Temporal adverb (“Temporaladverb”)
+    TemporalAdverb: "Temporaladverb";
+
+    /// Toponym (“Toponym”)
+    Toponym: "Toponym";
+
+    /// Verb (“Verb”)
+    Verb: "Verb";
+
+    /// Winged word (“Geflügeltes Wort”)
+    WingedWord: "Geflügeltes Wort";
+}
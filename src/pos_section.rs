@@ -18,6 +18,8 @@ pub fn parse_pos_section<'a>(
     let mut etymology = None;
     let mut examples = None;
     let mut feminine_forms = None;
+    let mut form_of = None;
+    let mut form_of_target = None;
     let mut hypernyms = None;
     let mut hyphenation = None;
     let mut hyponyms = None;
@@ -26,6 +28,7 @@ pub fn parse_pos_section<'a>(
     let mut no_longer_valid_spellings = None;
     let mut node_index = 0;
     let mut overview = None;
+    let mut overview_node = None;
     let mut pronunciation = None;
     let mut proverbs = None;
     let mut related_words = None;
@@ -33,7 +36,7 @@ pub fn parse_pos_section<'a>(
     let mut similar_words = None;
     let mut symbols = None;
     let mut synonyms = None;
-    let mut translations = false;
+    let mut translations = None;
     let mut typical_word_combinations = None;
     let mut variants = None;
     while let Some(node) = nodes.get(node_index) {
@@ -53,26 +56,25 @@ pub fn parse_pos_section<'a>(
                     }] = heading_child_nodes.as_slice()
                     {
                         if ::text_equals(name, "Übersetzungen") {
-                            if translations {
+                            if translations.is_some() {
                                 ::add_warning(context, node, ::WarningMessage::Duplicate);
                                 return 0;
                             }
-                            translations = true;
+                            translations = Some(vec![]);
                             if !parameters.is_empty() {
                                 ::add_warning(context, node, ::WarningMessage::ValueUnrecognized);
                                 continue;
                             }
-                            if let Some(node) = nodes.get(node_index) {
-                                if let ::Node::Template { name, .. } = node {
-                                    if ::text_equals(name, "Ü-Tabelle") {
-                                        node_index += 1;
-                                        ::add_warning(
-                                            context,
-                                            node,
-                                            ::WarningMessage::Supplementary,
-                                        );
-                                        continue;
-                                    }
+                            if let Some(::Node::Template {
+                                name, parameters, ..
+                            }) = nodes.get(node_index)
+                            {
+                                if ::text_equals(name, "Ü-Tabelle") {
+                                    node_index += 1;
+                                    translations = Some(::translations::parse_translations(
+                                        context, parameters,
+                                    ));
+                                    continue;
                                 }
                             }
                             ::add_warning(context, node, ::WarningMessage::SectionEmpty);
@@ -99,28 +101,42 @@ pub fn parse_pos_section<'a>(
                 node_index += 1;
                 if let Some(name) = ::parse_text(name) {
                     match &name as _ {
-                        "Abkürzungen" => section!(abbreviations::list::parse_list),
+                        "Abkürzungen" => section!(abbreviations::list::parse_relation_list),
                         "Abschnitte fehlen" | "Quellen" | "Referenzen prüfen"
                         | "Ähnlichkeiten 1" | "Ähnlichkeiten 2" => {
                             ::add_warning(context, node, ::WarningMessage::Supplementary);
                             continue;
                         }
                         "Aussprache" => section!(pronunciation::pronunciation::parse_pronunciation),
-                        "Bedeutungen" => section!(definitions::list::parse_list),
+                        "Bedeutungen" => section!(definitions::definitions::parse_definitions),
                         "Beispiele" => section!(examples::examples::parse_examples),
                         "Charakteristische Wortkombinationen" => {
                             section!(typical_word_combinations::list::parse_list)
                         }
-                        "Gegenwörter" => section!(antonyms::list::parse_list),
-                        "Herkunft" => section!(etymology::list::parse_list),
-                        "Koseformen" => section!(affectionate_forms::list::parse_list),
-                        "Kurzformen" => section!(short_forms::list::parse_list),
-                        "Männliche Wortformen" => section!(masculine_forms::list::parse_list),
-                        "Nebenformen" => section!(variants::list::parse_list),
+                        "Gegenwörter" => section!(antonyms::list::parse_relation_list),
+                        "Grammatische Merkmale" => {
+                            section!(form_of::form_of::parse_grammatical_features)
+                        }
+                        "Grundformverweis" | "Grundformverweis Dekl" | "Grundformverweis Konj" => {
+                            ::form_of::parse_base_form_reference(
+                                context,
+                                node,
+                                parameters,
+                                &mut form_of_target,
+                            );
+                            continue;
+                        }
+                        "Herkunft" => section!(etymology::etymology::parse_etymology),
+                        "Koseformen" => section!(affectionate_forms::list::parse_relation_list),
+                        "Kurzformen" => section!(short_forms::list::parse_relation_list),
+                        "Männliche Wortformen" => {
+                            section!(masculine_forms::list::parse_relation_list)
+                        }
+                        "Nebenformen" => section!(variants::list::parse_relation_list),
                         "Nicht mehr gültige Schreibweisen" => {
-                            section!(no_longer_valid_spellings::list::parse_list)
+                            section!(no_longer_valid_spellings::list::parse_relation_list)
                         }
-                        "Oberbegriffe" => section!(hypernyms::list::parse_list),
+                        "Oberbegriffe" => section!(hypernyms::list::parse_relation_list),
                         "Redewendungen" => section!(idioms::list::parse_list),
                         "Referenzen" => {
                             match nodes.get(node_index) {
@@ -132,16 +148,20 @@ pub fn parse_pos_section<'a>(
                             }
                             continue;
                         }
-                        "Sinnverwandte Wörter" => section!(related_words::list::parse_list),
+                        "Sinnverwandte Wörter" => {
+                            section!(related_words::list::parse_relation_list)
+                        }
                         "Sprichwörter" => section!(proverbs::list::parse_list),
                         "Symbole" => section!(symbols::list::parse_list),
-                        "Synonyme" => section!(synonyms::list::parse_list),
-                        "Unterbegriffe" => section!(hyponyms::list::parse_list),
-                        "Verkleinerungsformen" => section!(diminutives::list::parse_list),
-                        "Weibliche Wortformen" => section!(feminine_forms::list::parse_list),
+                        "Synonyme" => section!(synonyms::list::parse_relation_list),
+                        "Unterbegriffe" => section!(hyponyms::list::parse_relation_list),
+                        "Verkleinerungsformen" => section!(diminutives::list::parse_relation_list),
+                        "Weibliche Wortformen" => {
+                            section!(feminine_forms::list::parse_relation_list)
+                        }
                         "Wortbildungen" => section!(compound_words::list::parse_list),
-                        "Worttrennung" => section!(hyphenation::list::parse_list),
-                        "Ähnlichkeiten" => section!(similar_words::list::parse_list),
+                        "Worttrennung" => section!(hyphenation::hyphenation::parse_hyphenation),
+                        "Ähnlichkeiten" => section!(similar_words::list::parse_relation_list),
                         _ => if ::overview::parse_overview(
                             context,
                             node,
@@ -150,6 +170,7 @@ pub fn parse_pos_section<'a>(
                             &mut overview,
                         ) {
                             node_index += 1;
+                            overview_node = Some(node);
                             continue;
                         },
                     }
@@ -159,13 +180,46 @@ pub fn parse_pos_section<'a>(
         }
         ::add_warning(context, node, ::WarningMessage::Unrecognized);
     }
+    if form_of_target.is_some() {
+        form_of.get_or_insert_with(::FormOf::default).target = form_of_target;
+    }
+    let mut genders = vec![];
+    let mut parts_of_speech = vec![pos];
+    for flowing in &details {
+        match flowing {
+            ::Flowing::CommonGender => genders.push(::Gender::Common),
+            ::Flowing::FeminineGender => genders.push(::Gender::Feminine),
+            ::Flowing::MasculineGender => genders.push(::Gender::Masculine),
+            ::Flowing::NeuterGender => genders.push(::Gender::Neuter),
+            ::Flowing::Pos { pos } => parts_of_speech.push(*pos),
+            _ => {}
+        }
+    }
+    let overview = overview.unwrap_or_default();
+    if let (Some(node), Some(inflection)) = (overview_node, &overview.noun_inflection) {
+        // The heading and the overview template may list the same genders in different order.
+        if genders.is_empty() {
+            genders = inflection.genders.clone();
+        } else if !inflection.genders.is_empty()
+            && (genders.iter().any(|gender| !inflection.genders.contains(gender))
+                || inflection
+                    .genders
+                    .iter()
+                    .any(|gender| !genders.contains(gender)))
+        {
+            ::add_warning(context, node, ::WarningMessage::ValueConflicting);
+        }
+    }
+    let compound_words = compound_words.unwrap_or_default();
     let pronunciation = pronunciation.unwrap_or_default();
+    let word_formations = ::word_formations::parse_word_formations(&compound_words);
     pos_entries.push(::PosEntry {
         abbreviations: abbreviations.unwrap_or_default(),
         affectionate_forms: affectionate_forms.unwrap_or_default(),
         antonyms: antonyms.unwrap_or_default(),
         audio: pronunciation.audio,
-        compound_words: compound_words.unwrap_or_default(),
+        comparison: overview.comparison,
+        compound_words,
         definitions: definitions.unwrap_or_default(),
         details,
         diminutives: diminutives.unwrap_or_default(),
@@ -177,9 +231,13 @@ pub fn parse_pos_section<'a>(
         idioms: idioms.unwrap_or_default(),
         ipa: pronunciation.ipa,
         feminine_forms: feminine_forms.unwrap_or_default(),
+        form_of,
+        genders,
         masculine_forms: masculine_forms.unwrap_or_default(),
         no_longer_valid_spellings: no_longer_valid_spellings.unwrap_or_default(),
-        overview: overview.unwrap_or_default(),
+        noun_inflection: overview.noun_inflection,
+        overview: overview.overview,
+        parts_of_speech,
         pos,
         proverbs: proverbs.unwrap_or_default(),
         related_words: related_words.unwrap_or_default(),
@@ -188,8 +246,11 @@ pub fn parse_pos_section<'a>(
         similar_words: similar_words.unwrap_or_default(),
         symbols: symbols.unwrap_or_default(),
         synonyms: synonyms.unwrap_or_default(),
+        translations: translations.unwrap_or_default(),
         typical_word_combinations: typical_word_combinations.unwrap_or_default(),
         variants: variants.unwrap_or_default(),
+        verb_overview: overview.verb_overview,
+        word_formations,
     });
     node_index
 }
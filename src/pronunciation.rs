@@ -3,9 +3,9 @@
 // the file LICENSE at the top-level directory of this distribution.
 
 #[derive(Default)]
-pub struct Pronunciation<'a> {
-    pub audio: Vec<::Flowing<'a>>,
-    pub ipa: Vec<::Flowing<'a>>,
+pub struct PronunciationSection<'a> {
+    pub audio: Vec<::AudioSample<'a>>,
+    pub ipa: Vec<::Pronunciation<'a>>,
     pub rhymes: Vec<::Flowing<'a>>,
 }
 
@@ -14,7 +14,7 @@ pub fn parse_pronunciation<'a>(
     template_node: &::Node,
     parameters: &[::Parameter],
     nodes: &[::Node<'a>],
-    output: &mut Option<Pronunciation<'a>>,
+    output: &mut Option<PronunciationSection<'a>>,
 ) -> usize {
     ::parse_list_generic(
         context,
@@ -31,7 +31,7 @@ pub fn parse_pronunciation<'a>(
                     ::add_warning(context, item, ::WarningMessage::Unrecognized);
                     continue;
                 }
-                match item.nodes.get(0) {
+                match item.nodes.first() {
                     None => {
                         ::add_warning(context, item, ::WarningMessage::Empty);
                         continue;
@@ -44,11 +44,23 @@ pub fn parse_pronunciation<'a>(
                             if let Some(text) = ::parse_text(name) {
                                 match &text as _ {
                                     "Hörbeispiele" => {
-                                        parse_audio(context, item, node, parameters, &mut audio);
+                                        let mut flowing = audio.as_ref().map(|_| vec![]);
+                                        parse_audio(context, item, node, parameters, &mut flowing);
+                                        audio = Some(parse_audio_samples(
+                                            context,
+                                            item,
+                                            flowing.unwrap_or_default(),
+                                        ));
                                         continue;
                                     }
                                     "IPA" => {
-                                        parse_audio(context, item, node, parameters, &mut ipa);
+                                        let mut flowing = ipa.as_ref().map(|_| vec![]);
+                                        parse_audio(context, item, node, parameters, &mut flowing);
+                                        ipa = Some(parse_ipa(
+                                            context,
+                                            item,
+                                            flowing.unwrap_or_default(),
+                                        ));
                                         continue;
                                     }
                                     "Reime" => {
@@ -63,7 +75,7 @@ pub fn parse_pronunciation<'a>(
                     }
                 }
             }
-            Pronunciation {
+            PronunciationSection {
                 audio: audio.unwrap_or_default(),
                 ipa: ipa.unwrap_or_default(),
                 rhymes: rhymes.unwrap_or_default(),
@@ -180,12 +192,26 @@ fn parse_audio<'a>(
                         output_nodes.push(parse_template_rhyme(context, node, parameters));
                         continue;
                     }
-                    _ => {}
+                    _ => if let Some(label) = ::Label::from_abbreviation(&name) {
+                        output_nodes.push(::parse_simple_template(
+                            context,
+                            node,
+                            parameters,
+                            ::Flowing::Context {
+                                labels: vec![::LabelReference::Known(label)],
+                            },
+                        ));
+                        continue;
+                    },
                 }
             },
+            ::Node::Italic { .. } => {
+                output_nodes.push(::Flowing::Italic);
+                continue;
+            }
             ::Node::Text { mut value, .. } => {
                 if output_nodes.is_empty() {
-                    value = value.trim_left();
+                    value = value.trim_start();
                     if value.is_empty() {
                         continue;
                     }
@@ -209,6 +235,148 @@ fn parse_audio<'a>(
     *output = Some(output_nodes);
 }
 
+// The audio sample of the lemma is followed by the audio samples of other forms, each following a template indicating the form, such as `{{Audio|De-Haus.ogg}}, {{Pl.}} {{Audio|De-Häuser.ogg}}`. A regional label such as `österr.:` applies to the audio sample following it when the file name doesn't indicate the region.
+fn parse_audio_samples<'a>(
+    context: &mut ::Context<'a>,
+    list_item: &::DefinitionListItem<'a>,
+    flowing: Vec<::Flowing<'a>>,
+) -> Vec<::AudioSample<'a>> {
+    let mut form = None;
+    let mut region = None;
+    let mut samples = vec![];
+    for item in flowing {
+        form = Some(match item {
+            ::Flowing::Audio {
+                file_name,
+                label,
+                language,
+            } => {
+                samples.push(::AudioSample {
+                    form,
+                    label,
+                    language,
+                    region: ::audio_sample::parse_region(&file_name).or(region.take()),
+                    file_name,
+                });
+                continue;
+            }
+            ::Flowing::Comparative => ::WordForm::Comparative,
+            ::Flowing::Context { labels } => {
+                for label in labels {
+                    match label {
+                        ::LabelReference::Known(label) if label.kind() == ::LabelKind::Region => {
+                            region = Some(label)
+                        }
+                        _ => ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized),
+                    }
+                }
+                continue;
+            }
+            ::Flowing::EmptyAudio | ::Flowing::Italic | ::Flowing::Unknown { .. } => continue,
+            ::Flowing::Genitive => ::WordForm::Genitive,
+            ::Flowing::PastParticiple => ::WordForm::PastParticiple,
+            ::Flowing::Plural => ::WordForm::Plural,
+            ::Flowing::Plural1 => ::WordForm::Plural1,
+            ::Flowing::Plural2 => ::WordForm::Plural2,
+            ::Flowing::Plural3 => ::WordForm::Plural3,
+            ::Flowing::Plural4 => ::WordForm::Plural4,
+            ::Flowing::Preterite => ::WordForm::Preterite,
+            ::Flowing::Superlative => ::WordForm::Superlative,
+            ::Flowing::Text { value } => {
+                for label in value.split(&[',', ';', ':'] as &[_]) {
+                    let label = label.trim();
+                    if label.is_empty() || ["auch", "oder", "und"].contains(&label) {
+                        continue;
+                    }
+                    match ::Label::from_name(label) {
+                        Some(label) if label.kind() == ::LabelKind::Region => region = Some(label),
+                        _ => ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized),
+                    }
+                }
+                continue;
+            }
+            _ => {
+                ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+                continue;
+            }
+        });
+        region = None;
+    }
+    samples
+}
+
+// The pronunciation of the lemma is followed by the pronunciation of other forms, each following a template indicating the form, such as `{{Lautschrift|ˈbaŋk}}, {{Pl.}} {{Lautschrift|ˈbɛŋkə}}`. Labels such as `österr.:` apply to the pronunciation following them, and labels in parentheses apply to the pronunciation preceding them.
+fn parse_ipa<'a>(
+    context: &mut ::Context<'a>,
+    list_item: &::DefinitionListItem<'a>,
+    flowing: Vec<::Flowing<'a>>,
+) -> Vec<::Pronunciation<'a>> {
+    let mut form = None;
+    let mut labels = vec![];
+    let mut pronunciations: Vec<::Pronunciation> = vec![];
+    for item in flowing {
+        form = Some(match item {
+            ::Flowing::Comparative => ::WordForm::Comparative,
+            ::Flowing::Context {
+                labels: mut context_labels,
+            } => {
+                labels.append(&mut context_labels);
+                continue;
+            }
+            ::Flowing::Genitive => ::WordForm::Genitive,
+            ::Flowing::Ipa { ipa } => {
+                pronunciations.push(::Pronunciation {
+                    form,
+                    ipa,
+                    labels: ::std::mem::take(&mut labels),
+                });
+                continue;
+            }
+            ::Flowing::Italic => continue,
+            ::Flowing::PastParticiple => ::WordForm::PastParticiple,
+            ::Flowing::Plural => ::WordForm::Plural,
+            ::Flowing::Plural1 => ::WordForm::Plural1,
+            ::Flowing::Plural2 => ::WordForm::Plural2,
+            ::Flowing::Plural3 => ::WordForm::Plural3,
+            ::Flowing::Plural4 => ::WordForm::Plural4,
+            ::Flowing::Preterite => ::WordForm::Preterite,
+            ::Flowing::Superlative => ::WordForm::Superlative,
+            ::Flowing::Text { value } => {
+                for label in value.split(&[',', ';', ':'] as &[_]) {
+                    let label = label.trim();
+                    if label.is_empty() || ["auch", "oder", "und"].contains(&label) {
+                        continue;
+                    }
+                    if !(label.starts_with('(') && label.ends_with(')')) {
+                        labels.push(::LabelReference::from_name(::Cow::Owned(label.to_string())));
+                        continue;
+                    }
+                    let label = label[1..label.len() - 1].trim().to_string();
+                    match pronunciations.last_mut() {
+                        None => {
+                            ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized)
+                        }
+                        Some(pronunciation) => pronunciation
+                            .labels
+                            .push(::LabelReference::from_name(::Cow::Owned(label))),
+                    }
+                }
+                continue;
+            }
+            ::Flowing::Unknown { .. } => continue,
+            _ => {
+                ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+                continue;
+            }
+        });
+        labels.clear();
+    }
+    if !labels.is_empty() {
+        ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+    }
+    pronunciations
+}
+
 macro_rules! parse_parameter {
     ($output:tt $context:tt $template_node:tt $parameter:tt) => {{
         $output = ::parse_text_not_empty(&$parameter.value);
@@ -275,7 +443,7 @@ fn parse_template_audio<'a>(
         Some(file_name) => ::Flowing::Audio {
             file_name,
             label,
-            language,
+            language: language.map(::LanguageReference::from_code),
         },
     }
 }
@@ -286,9 +454,6 @@ fn parse_template_ipa<'a>(
     parameters: &[::Parameter<'a>],
 ) -> ::Flowing<'a> {
     if let [parameter @ ::Parameter { name: None, .. }] = parameters {
-        if let Some(ipa) = ::parse_text_not_empty(&parameter.value) {
-            return ::Flowing::Ipa { ipa };
-        }
         match ::parse_text_not_empty(&parameter.value) {
             None => ::create_unknown2(
                 context,
@@ -296,7 +461,15 @@ fn parse_template_ipa<'a>(
                 parameter,
                 ::WarningMessage::ValueUnrecognized,
             ),
-            Some(ipa) => ::Flowing::Ipa { ipa },
+            Some(ipa) => {
+                if ::tokenize_ipa(&ipa)
+                    .iter()
+                    .any(|token| matches!(token, ::IpaToken::Invalid(_)))
+                {
+                    ::add_warning(context, parameter, ::WarningMessage::IpaInvalid);
+                }
+                ::Flowing::Ipa { ipa }
+            }
         }
     } else {
         ::create_unknown(context, template_node, ::WarningMessage::ValueUnrecognized)
@@ -0,0 +1,104 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+impl ::RhymeIndex {
+    /// Creates an empty index.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the rhymes from a parsed page.
+    ///
+    /// `title` is the title of the page. The title is added to each rhyme given in the section [`Aussprache`](https://de.wiktionary.org/wiki/Vorlage:Aussprache) of each entry once for each of the parts of speech of the entry. The number of syllables is the number of syllables of the first alternative of the hyphenation of the lemma. If the word was already added from a page in the namespace `Reim` without a part of speech, that entry is replaced.
+    pub fn add_output(&mut self, title: &str, output: &::Output) {
+        for language_entry in &output.language_entries {
+            for pos_entry in &language_entry.pos_entries {
+                let syllables = pos_entry
+                    .hyphenation
+                    .iter()
+                    .flatten()
+                    .find(|hyphenation| hyphenation.form.is_none())
+                    .and_then(|hyphenation| hyphenation.alternatives.first())
+                    .map(Vec::len);
+                for rhyme in &pos_entry.rhymes {
+                    if let ::Flowing::Rhyme { rhyme } = rhyme {
+                        for &pos in &pos_entry.parts_of_speech {
+                            let entries = self.rhymes.entry(normalize(rhyme)).or_default();
+                            let mut entry = ::RhymeIndexEntry {
+                                in_entry: true,
+                                in_rhyme_page: false,
+                                language: language_entry.language,
+                                lemma: title.to_string(),
+                                pos: Some(pos),
+                                syllables,
+                            };
+                            for existing in entries.iter() {
+                                if existing.language == entry.language && existing.lemma == title {
+                                    entry.in_rhyme_page |= existing.in_rhyme_page;
+                                    if existing.pos.is_none() {
+                                        entry.syllables = entry.syllables.or(existing.syllables);
+                                    }
+                                }
+                            }
+                            entries.retain(|existing| {
+                                existing.pos.is_some()
+                                    || existing.language != entry.language
+                                    || existing.lemma != title
+                            });
+                            if !entries.contains(&entry) {
+                                entries.push(entry);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds the words from a parsed page in the namespace `Reim`.
+    ///
+    /// Words already added from a dictionary page in the same language are marked as listed on the page of the rhyme. Other words are added without a part of speech. Nothing is added if the language of the page is not recognized.
+    pub fn add_rhyme_page(&mut self, output: &::RhymeOutput) {
+        let language = match output.language {
+            None => return,
+            Some(language) => language,
+        };
+        let entries = self.rhymes.entry(normalize(&output.rhyme)).or_default();
+        for word in &output.words {
+            let mut found = false;
+            for entry in entries.iter_mut() {
+                if entry.language == language && entry.lemma == word.word {
+                    entry.in_rhyme_page = true;
+                    if entry.syllables.is_none() {
+                        entry.syllables = word.syllables;
+                    }
+                    found = true;
+                }
+            }
+            if !found {
+                entries.push(::RhymeIndexEntry {
+                    in_entry: false,
+                    in_rhyme_page: true,
+                    language,
+                    lemma: word.word.to_string(),
+                    pos: None,
+                    syllables: word.syllables,
+                });
+            }
+        }
+    }
+
+    /// Returns the entries of the given rhyme, which may be written with or without the leading hyphen.
+    #[must_use]
+    pub fn get(&self, rhyme: &str) -> &[::RhymeIndexEntry] {
+        self.rhymes
+            .get(&normalize(rhyme))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+fn normalize(rhyme: &str) -> String {
+    rhyme.trim().trim_start_matches('-').to_string()
+}
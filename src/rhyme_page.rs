@@ -0,0 +1,75 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_rhyme_page<'a>(
+    context: &mut ::Context<'a>,
+    nodes: &[::Node<'a>],
+) -> Vec<::RhymeWord<'a>> {
+    let mut words = vec![];
+    let mut syllables = None;
+    parse_nodes(context, nodes, &mut syllables, &mut words);
+    words
+}
+
+fn parse_nodes<'a>(
+    context: &mut ::Context<'a>,
+    nodes: &[::Node<'a>],
+    syllables: &mut Option<usize>,
+    words: &mut Vec<::RhymeWord<'a>>,
+) {
+    for node in nodes {
+        match node {
+            ::Node::Bold { .. }
+            | ::Node::BoldItalic { .. }
+            | ::Node::Category { .. }
+            | ::Node::Comment { .. }
+            | ::Node::Italic { .. }
+            | ::Node::ParagraphBreak { .. }
+            | ::Node::Text { .. } => {}
+            ::Node::Heading { nodes, .. } => {
+                *syllables = ::parse_text(nodes).and_then(|heading| parse_syllables(&heading))
+            }
+            // Links to other namespaces, such as to other rhymes, and links to sections of the same page are not words. Links to a section of another page, such as `[[Haus#Deutsch|Haus]]`, give the page as the word.
+            ::Node::Link { target, .. } => {
+                let word = &target[..target.find('#').unwrap_or(target.len())];
+                if !word.is_empty() && !word.contains(':') {
+                    words.push(::RhymeWord {
+                        syllables: *syllables,
+                        word: ::Cow::Borrowed(word),
+                    });
+                }
+            }
+            ::Node::DefinitionList { items, .. } => {
+                for item in items {
+                    parse_nodes(context, &item.nodes, syllables, words);
+                }
+            }
+            ::Node::OrderedList { items, .. } | ::Node::UnorderedList { items, .. } => {
+                for item in items {
+                    parse_nodes(context, &item.nodes, syllables, words);
+                }
+            }
+            _ => ::add_warning(context, node, ::WarningMessage::Unrecognized),
+        }
+    }
+}
+
+// Headings give the number of syllables either as a number followed by “Silbe” or “Silben”, such as `2 Silben`, or as an adjective, such as `Zweisilbige Reime`.
+fn parse_syllables(heading: &str) -> Option<usize> {
+    let heading = heading.trim().to_lowercase();
+    let mut words = heading.split_whitespace();
+    let first = words.next()?;
+    if let Ok(syllables) = first.parse() {
+        return match words.next() {
+            Some("silbe") | Some("silben") => Some(syllables),
+            _ => None,
+        };
+    }
+    [
+        "ein", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun", "zehn",
+    ]
+    .iter()
+    .position(|number| first.starts_with(number) && first[number.len()..].starts_with("silbig"))
+    .map(|index| index + 1)
+}
@@ -0,0 +1,193 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Ranges larger than this are assumed to be typing mistakes rather than references to actual senses.
+const MAX_RANGE_LENGTH: u32 = 50;
+
+#[must_use]
+pub fn parse_leading_senses(
+    flowing: &mut Vec<::Flowing>,
+    previous: Option<&::Sense>,
+) -> Option<Vec<::Sense>> {
+    let senses = match flowing.first_mut() {
+        Some(::Flowing::Text { value }) if value.starts_with('[') => {
+            let end = value.find(']')?;
+            let senses = parse_senses(&value[1..end], previous)?;
+            let start = value.len() - value[end + 1..].trim_start().len();
+            *value = ::slice_cow(value, start, value.len());
+            senses
+        }
+        _ => return None,
+    };
+    if let Some(::Flowing::Text { value }) = flowing.first() {
+        if value.is_empty() {
+            flowing.remove(0);
+        }
+    }
+    Some(senses)
+}
+
+// For list items where the sense reference is optional. A reference that can't be parsed is left in the content with a warning.
+#[must_use]
+pub fn parse_optional_senses(
+    context: &mut ::Context,
+    list_item: &::DefinitionListItem,
+    flowing: &mut Vec<::Flowing>,
+) -> Vec<::Sense> {
+    match parse_leading_senses(flowing, None) {
+        None => {
+            if let Some(::Flowing::Text { value }) = flowing.first() {
+                if value.starts_with('[') {
+                    ::add_warning(context, list_item, ::WarningMessage::ValueUnrecognized);
+                }
+            }
+            vec![]
+        }
+        Some(senses) => senses,
+    }
+}
+
+// A sense without a number, such as the `b` in `2a, b`, takes its number from the sense before it, or from `previous` when it comes first.
+#[must_use]
+pub fn parse_senses(text: &str, previous: Option<&::Sense>) -> Option<Vec<::Sense>> {
+    let mut senses = vec![];
+    for item in text.split(',') {
+        let item = item.trim();
+        let (start, end) = match item.find(|character| "-–—".contains(character)) {
+            None => (item, None),
+            Some(index) => {
+                let (start, end) = item.split_at(index);
+                let dash_length = end.chars().next().unwrap().len_utf8();
+                (start.trim_end(), Some(end[dash_length..].trim_start()))
+            }
+        };
+        let start = parse_sense(start, senses.last().or(previous))?;
+        match end {
+            None => senses.push(start),
+            Some(end) => {
+                let end = parse_sense(end, Some(&start))?;
+                match (start.letter, end.letter) {
+                    (None, None) => {
+                        if end.number < start.number || end.number - start.number > MAX_RANGE_LENGTH
+                        {
+                            return None;
+                        }
+                        senses.extend((start.number..=end.number).map(|number| ::Sense {
+                            letter: None,
+                            number,
+                        }));
+                    }
+                    (Some(start_letter), Some(end_letter)) => {
+                        if end.number != start.number || end_letter < start_letter {
+                            return None;
+                        }
+                        senses.extend((start_letter..=end_letter).map(|letter| ::Sense {
+                            letter: Some(letter),
+                            number: start.number,
+                        }));
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    Some(senses)
+}
+
+fn parse_sense(text: &str, previous: Option<&::Sense>) -> Option<::Sense> {
+    let digits_length = text
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(text.len());
+    let (digits, letter) = text.split_at(digits_length);
+    let number = if digits.is_empty() {
+        previous?.number
+    } else {
+        digits.parse().ok().filter(|number| *number > 0)?
+    };
+    let mut letters = letter.chars();
+    let letter = match (letters.next(), letters.next()) {
+        (None, _) => None,
+        (Some(letter @ 'a'..='z'), None) => Some(letter),
+        _ => return None,
+    };
+    if digits.is_empty() && letter.is_none() {
+        return None;
+    }
+    Some(::Sense { letter, number })
+}
+
+#[must_use]
+pub fn split_at_senses<'a>(flowing: Vec<::Flowing<'a>>) -> Vec<(Vec<::Sense>, Vec<::Flowing<'a>>)> {
+    let mut groups = vec![(vec![], vec![])];
+    for node in flowing {
+        match node {
+            ::Flowing::Text { value } => {
+                let mut position = 0;
+                while let Some((start, end, senses)) = find_senses(&value[position..]) {
+                    push_text(&mut groups, ::slice_cow(&value, position, position + start));
+                    groups.push((senses, vec![]));
+                    position += end;
+                }
+                push_text(&mut groups, ::slice_cow(&value, position, value.len()));
+            }
+            node => groups.last_mut().unwrap().1.push(node),
+        }
+    }
+    groups
+        .into_iter()
+        .filter_map(|(senses, mut flowing)| {
+            trim_flowing(&mut flowing);
+            if flowing.is_empty() {
+                None
+            } else {
+                Some((senses, flowing))
+            }
+        })
+        .collect()
+}
+
+pub fn find_senses(text: &str) -> Option<(usize, usize, Vec<::Sense>)> {
+    let mut position = 0;
+    while let Some(start) = text[position..].find('[') {
+        let start = position + start;
+        let end = start + text[start..].find(']')?;
+        if let Some(senses) = parse_senses(&text[start + 1..end], None) {
+            return Some((start, end + 1, senses));
+        }
+        position = start + 1;
+    }
+    None
+}
+
+fn push_text<'a>(groups: &mut Vec<(Vec<::Sense>, Vec<::Flowing<'a>>)>, value: ::Cow<'a, str>) {
+    if !value.is_empty() {
+        groups.last_mut().unwrap().1.push(::Flowing::Text { value });
+    }
+}
+
+// Removes whitespace and separators left over at the edges after splitting text at sense references.
+fn trim_flowing(flowing: &mut Vec<::Flowing>) {
+    if let Some(::Flowing::Text { value }) = flowing.first_mut() {
+        let start = value.len() - value.trim_start().len();
+        *value = ::slice_cow(value, start, value.len());
+    }
+    if let Some(::Flowing::Text { value }) = flowing.first() {
+        if value.is_empty() {
+            flowing.remove(0);
+        }
+    }
+    if let Some(::Flowing::Text { value }) = flowing.last_mut() {
+        let end = value
+            .trim_end_matches(|character: char| {
+                character.is_whitespace() || ",;".contains(character)
+            })
+            .len();
+        *value = ::slice_cow(value, 0, end);
+    }
+    if let Some(::Flowing::Text { value }) = flowing.last() {
+        if value.is_empty() {
+            flowing.pop();
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use parse_wiki_text::Positioned;
+
+struct Line<'a, 'b> {
+    end: usize,
+    nodes: Vec<&'b ::Node<'a>>,
+    start: usize,
+}
+
+impl<'a, 'b> Positioned for Line<'a, 'b> {
+    fn end(&self) -> usize {
+        self.end
+    }
+
+    fn start(&self) -> usize {
+        self.start
+    }
+}
+
+pub fn parse_translations<'a>(
+    context: &mut ::Context<'a>,
+    parameters: &[::Parameter<'a>],
+) -> Vec<::Translation<'a>> {
+    let mut translations = vec![];
+    for parameter in parameters {
+        match ::parse_parameter_name(parameter) {
+            Some("Dialekttabelle") => {
+                ::add_warning(context, parameter, ::WarningMessage::Supplementary)
+            }
+            Some("Ü-links") | Some("Ü-rechts") => {
+                parse_translation_list(context, &parameter.value, &mut translations)
+            }
+            _ => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+        }
+    }
+    translations
+}
+
+fn parse_translation_list<'a>(
+    context: &mut ::Context<'a>,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::Translation<'a>>,
+) {
+    // Lists are not parsed inside template parameters, so the text is split into lines here and the list markers are removed from each line.
+    let text_lines: Vec<Vec<_>> = nodes
+        .iter()
+        .map(|node| match node {
+            ::Node::Text { start, value, .. } => {
+                let mut position = *start;
+                value
+                    .split('\n')
+                    .map(|value| {
+                        let node = ::Node::Text {
+                            end: position + value.len(),
+                            start: position,
+                            value,
+                        };
+                        position += value.len() + 1;
+                        node
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        })
+        .collect();
+    let mut lines = vec![];
+    let mut line = vec![];
+    for (node, text_lines) in nodes.iter().zip(&text_lines) {
+        match node {
+            ::Node::Text { .. } => {
+                for (index, text_line) in text_lines.iter().enumerate() {
+                    if index > 0 {
+                        lines.push(line);
+                        line = vec![];
+                    }
+                    line.push(text_line);
+                }
+            }
+            _ => line.push(node),
+        }
+    }
+    lines.push(line);
+    for nodes in lines {
+        if let (Some(first_node), Some(last_node)) = (nodes.first(), nodes.last()) {
+            let line = Line {
+                end: last_node.end(),
+                start: first_node.start(),
+                nodes,
+            };
+            parse_translation(context, &line, output);
+        }
+    }
+}
+
+fn parse_translation<'a>(
+    context: &mut ::Context<'a>,
+    line: &Line<'a, '_>,
+    output: &mut Vec<::Translation<'a>>,
+) {
+    let mut flowing = ::list::parse_list_item(context, line.nodes.iter().cloned(), false);
+    let is_blank = match flowing.as_slice() {
+        [] => true,
+        [::Flowing::Text { value }] => value.trim().is_empty(),
+        _ => false,
+    };
+    if is_blank {
+        return;
+    }
+    if let Some(::Flowing::Text { value }) = flowing.first_mut() {
+        // Lines nested under a language contain translations into a variety of that language and carry their own language template.
+        let start = value.len() - value.trim_start_matches(&['*', ':'] as &[_]).len();
+        *value = ::slice_cow(value, start, value.len());
+    }
+    if let Some(::Flowing::Text { value }) = flowing.first() {
+        if value.is_empty() {
+            flowing.remove(0);
+        }
+    }
+    let mut terms = flowing.split_off(flowing.len().min(1));
+    match (flowing.pop(), terms.first_mut()) {
+        (Some(::Flowing::Language { language }), Some(::Flowing::Text { value }))
+            if value.starts_with(':') =>
+        {
+            *value = ::slice_cow(value, 1, value.len());
+            let mut items = vec![];
+            for (senses, terms) in ::senses::split_at_senses(terms) {
+                if senses.is_empty() {
+                    ::add_warning(context, line, ::WarningMessage::ValueUnrecognized);
+                }
+                items.push(::TranslationItem { senses, terms });
+            }
+            if !items.is_empty() {
+                output.push(::Translation { items, language });
+            }
+        }
+        (node, _) => {
+            if node.is_none() && terms.is_empty() {
+                ::add_warning(context, line, ::WarningMessage::Empty);
+            } else {
+                ::add_warning(context, line, ::WarningMessage::ValueUnrecognized);
+            }
+        }
+    }
+}
@@ -43,6 +43,11 @@ pub fn create_unknown2<'a>(
     }
 }
 
+#[must_use]
+pub fn is_false(value: &bool) -> bool {
+    !value
+}
+
 #[must_use]
 pub fn parse_link<'a>(
     context: &mut Context<'a>,
@@ -109,7 +114,7 @@ pub fn parse_list_generic<'a, T: Default>(
         add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
         return 0;
     }
-    if let Some(::Node::DefinitionList { items, .. }) = nodes.get(0) {
+    if let Some(::Node::DefinitionList { items, .. }) = nodes.first() {
         *output = Some(parse_list(context, items));
         return 1;
     }
@@ -165,6 +170,14 @@ pub fn parse_text_not_empty<'a>(nodes: &[::Node<'a>]) -> Option<::Cow<'a, str>>
     parse_text(nodes).filter(|text| !text.is_empty())
 }
 
+#[must_use]
+pub fn slice_cow<'a>(text: &::Cow<'a, str>, start: usize, end: usize) -> ::Cow<'a, str> {
+    match text {
+        ::Cow::Borrowed(text) => ::Cow::Borrowed(&text[start..end]),
+        ::Cow::Owned(text) => ::Cow::Owned(text[start..end].to_string()),
+    }
+}
+
 #[must_use]
 pub fn text_equals(nodes: &[::Node], text: &str) -> bool {
     match parse_text(nodes) {
@@ -172,3 +185,9 @@ pub fn text_equals(nodes: &[::Node], text: &str) -> bool {
         Some(value) => value == text,
     }
 }
+
+#[must_use]
+pub fn trim_cow<'a>(text: &::Cow<'a, str>) -> ::Cow<'a, str> {
+    let start = text.len() - text.trim_start().len();
+    slice_cow(text, start, start + text.trim().len())
+}
@@ -0,0 +1,57 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_verb_overview<'a>(
+    context: &mut ::Context<'a>,
+    parameters: &[::Parameter<'a>],
+) -> ::VerbOverview<'a> {
+    let mut overview = ::VerbOverview::default();
+    for parameter in parameters {
+        // Variants of a form are written as the same parameter name followed by `*` or a number, such as `Präsens_ich*` or `Imperativ Singular 2`.
+        let (name, variant, alternative) = match ::overview::parse_parameter(context, parameter) {
+            None => continue,
+            Some(parsed) => parsed,
+        };
+        let forms = match name {
+            "Bild" => continue,
+            "Hilfsverb" => {
+                for value in ::parse_text(&parameter.value).unwrap().split(',') {
+                    let auxiliary = match value.trim() {
+                        "" => continue,
+                        "haben" => ::Auxiliary::Have,
+                        "sein" => ::Auxiliary::Be,
+                        _ => {
+                            ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                            continue;
+                        }
+                    };
+                    if !overview.auxiliaries.contains(&auxiliary) {
+                        overview.auxiliaries.push(auxiliary);
+                    }
+                }
+                continue;
+            }
+            "Imperativ Plural" => &mut overview.imperative_plural,
+            "Imperativ Singular" => &mut overview.imperative_singular,
+            "Konjunktiv II_ich" => &mut overview.subjunctive_ii_first_singular,
+            "Partizip II" => &mut overview.past_participle,
+            "Präsens_du" => &mut overview.present_second_singular,
+            "Präsens_er, sie, es" => &mut overview.present_third_singular,
+            "Präsens_ich" => &mut overview.present_first_singular,
+            "Präteritum_ich" => &mut overview.preterite_first_singular,
+            _ => {
+                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                continue;
+            }
+        };
+        if let Some(form) = ::overview::parse_form(parameter) {
+            forms.push(::OverviewForm {
+                alternative,
+                form,
+                variant,
+            });
+        }
+    }
+    overview
+}
@@ -0,0 +1,72 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Splits each list item into groups at each part of speech given by the template `Wortbildung` and each label followed by a colon, such as `Adjektive: [[häuslich]]; Konversionen: [[hausen]]`. The label of a group is the text before the colon following the last comma or semicolon.
+pub fn parse_word_formations<'a>(list_items: &[::ListItem<'a>]) -> Vec<::WordFormationGroup<'a>> {
+    let mut groups = vec![];
+    for list_item in list_items {
+        let mut group = create_group(list_item, None, None);
+        for item in &list_item.content {
+            match item {
+                ::Flowing::Link { target, .. } => group.lemmas.push(target.clone()),
+                ::Flowing::Pos { pos } => {
+                    start_group(&mut groups, &mut group, list_item, None, Some(*pos))
+                }
+                ::Flowing::Text { value } => {
+                    let mut start = 0;
+                    for (index, _) in value.match_indices(':') {
+                        let label_start = value[..index]
+                            .rfind([',', ';'])
+                            .map_or(start, |separator| separator + 1)
+                            .max(start);
+                        let label = ::trim_cow(&::slice_cow(value, label_start, index));
+                        start = index + 1;
+                        if label.is_empty() {
+                            continue;
+                        }
+                        match ::list::parse_word_formation_pos(&label) {
+                            None => {
+                                start_group(&mut groups, &mut group, list_item, Some(label), None)
+                            }
+                            Some(pos) => {
+                                start_group(&mut groups, &mut group, list_item, None, Some(pos))
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if !group.lemmas.is_empty() {
+            groups.push(group);
+        }
+    }
+    groups
+}
+
+fn create_group<'a>(
+    list_item: &::ListItem,
+    label: Option<::Cow<'a, str>>,
+    pos: Option<::Pos>,
+) -> ::WordFormationGroup<'a> {
+    ::WordFormationGroup {
+        label,
+        lemmas: vec![],
+        pos,
+        senses: list_item.senses.clone(),
+    }
+}
+
+fn start_group<'a>(
+    groups: &mut Vec<::WordFormationGroup<'a>>,
+    group: &mut ::WordFormationGroup<'a>,
+    list_item: &::ListItem,
+    label: Option<::Cow<'a, str>>,
+    pos: Option<::Pos>,
+) {
+    let group = ::std::mem::replace(group, create_group(list_item, label, pos));
+    if !group.lemmas.is_empty() {
+        groups.push(group);
+    }
+}
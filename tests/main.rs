@@ -13,3 +13,1345 @@ fn main() {
         &parse_wiktionary_de::create_configuration().parse("").nodes,
     );
 }
+
+#[test]
+fn translations() {
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "==== {{Übersetzungen}} ====\n",
+        "{{Ü-Tabelle|Ü-links=\n",
+        "*{{en}}: [1] {{Ü|en|house}}; [2, 3] {{Ü|en|home}}\n",
+        "*{{fr}}: [1] \n",
+        "*Latein: [1] ''domus''\n",
+        "|Ü-rechts=\n",
+        "*{{sv}}: [1–2] {{Ü|sv|hus}} {{n}}\n",
+        "*{{zh}}:\n",
+        "**{{zh-cn}}: [1] {{Ü|zh|房子}}\n",
+        "**{{zh-tw}}: [1] {{Ü|zh|房子}}\n",
+        "*{{KA}}: [1] hus\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_de::WarningMessage::ValueUnrecognized
+    );
+    let translations = &output.language_entries[0].pos_entries[0].translations;
+    assert_eq!(translations.len(), 5);
+    assert_eq!(
+        translations[0].language,
+        parse_wiktionary_de::LanguageReference::Known(parse_wiktionary_de::Language::En)
+    );
+    assert_eq!(translations[0].items.len(), 2);
+    assert_eq!(
+        translations[0].items[1]
+            .senses
+            .iter()
+            .map(|sense| sense.number)
+            .collect::<Vec<_>>(),
+        [2, 3]
+    );
+    assert_eq!(translations[1].language.code(), "sv");
+    assert_eq!(translations[1].items[0].senses.len(), 2);
+    assert_eq!(translations[1].items[0].terms.len(), 3);
+    assert_eq!(translations[2].language.code(), "zh-cn");
+    assert_eq!(translations[3].language.code(), "zh-tw");
+    assert_eq!(
+        translations[4].language,
+        parse_wiktionary_de::LanguageReference::Unknown("KA".into())
+    );
+    assert_eq!(translations[4].language.language(), None);
+}
+
+#[test]
+fn language_adjectives() {
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Herkunft}}\n",
+        ":{{ahd.}}, {{mhd.}}, {{lat.}}, {{mlg.}}, {{frühnhd.}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let languages = output.language_entries[0].pos_entries[0].etymology[0]
+        .content
+        .iter()
+        .filter_map(|item| match item {
+            parse_wiktionary_de::Flowing::LanguageAdjective { language } => Some(language),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        languages
+            .iter()
+            .map(|language| language.language())
+            .collect::<Vec<_>>(),
+        [
+            Some(parse_wiktionary_de::Language::Goh),
+            Some(parse_wiktionary_de::Language::Gmh),
+            Some(parse_wiktionary_de::Language::La),
+            Some(parse_wiktionary_de::Language::Gml),
+            None
+        ]
+    );
+    assert_eq!(languages[4].code(), "frühnhd");
+}
+
+#[test]
+fn definitions() {
+    let wiki_text = concat!(
+        "== Bank ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Bedeutungen}}\n",
+        ":[1] Sitzgelegenheit\n",
+        ":[2] Kreditinstitut\n",
+        "::[a] Unternehmen\n",
+        ":[2b] Gebäude"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bank",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let definitions = &output.language_entries[0].pos_entries[0].definitions;
+    assert_eq!(definitions.len(), 2);
+    assert_eq!(definitions[1].sense.unwrap().number, 2);
+    assert_eq!(
+        definitions[1]
+            .subsenses
+            .iter()
+            .map(|definition| definition.sense.unwrap().letter)
+            .collect::<Vec<_>>(),
+        [Some('a'), Some('b')]
+    );
+}
+
+#[test]
+fn overview() {
+    let wiki_text = concat!(
+        "== schön ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Adjektiv|Deutsch}} ===\n",
+        "{{Deutsch Adjektiv Übersicht\n",
+        "|Positiv=schön\n",
+        "|Komparativ=schöner\n",
+        "|Superlativ=schönsten\n",
+        "|Superlativ*=schönesten\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "schön",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let pos_entry = &output.language_entries[0].pos_entries[0];
+    let comparison = pos_entry.comparison.as_ref().unwrap();
+    assert_eq!(comparison.comparative[0].form, "schöner");
+    assert_eq!(comparison.superlative[0].form, "schönsten");
+    assert_eq!(comparison.predicative_superlative[0].form, "am schönsten");
+    assert!(comparison.superlative[1].alternative);
+    assert_eq!(comparison.predicative_superlative[1].form, "am schönesten");
+    assert_eq!(
+        pos_entry.overview.as_ref().unwrap().named_parameters.len(),
+        4
+    );
+}
+
+#[test]
+fn flexion() {
+    let wiki_text = "{{Deutsch Verb unregelmäßig|2=geb|3=gab|4=gäb|5=gegeben|6=gib|vp=ja}}";
+    let output = parse_wiktionary_de::parse_flexion(
+        "Flexion:geben",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let forms = &output.paradigms[0].forms;
+    let find = |features: parse_wiktionary_de::Features| {
+        forms
+            .iter()
+            .find(|form| form.features == features)
+            .map(|form| &form.form as &str)
+    };
+    assert_eq!(
+        find(parse_wiktionary_de::Features {
+            mood: Some(parse_wiktionary_de::Mood::Indicative),
+            number: Some(parse_wiktionary_de::Number::Singular),
+            person: Some(parse_wiktionary_de::Person::Third),
+            tense: Some(parse_wiktionary_de::Tense::Present),
+            voice: Some(parse_wiktionary_de::Voice::Active),
+            ..Default::default()
+        }),
+        Some("gibt")
+    );
+    assert_eq!(
+        find(parse_wiktionary_de::Features {
+            mood: Some(parse_wiktionary_de::Mood::SubjunctiveIi),
+            number: Some(parse_wiktionary_de::Number::Plural),
+            person: Some(parse_wiktionary_de::Person::First),
+            tense: Some(parse_wiktionary_de::Tense::Pluperfect),
+            voice: Some(parse_wiktionary_de::Voice::ProcessPassive),
+            ..Default::default()
+        }),
+        Some("wären gegeben worden")
+    );
+    for (stem, infinitive, past_participle, ambiguous) in &[
+        ("feier", "feiern", Some("gefeiert"), false),
+        ("steuer", "steuern", Some("gesteuert"), false),
+        ("studier", "studieren", Some("studiert"), false),
+        ("bettel", "betteln", Some("gebettelt"), false),
+        ("beicht", "beichten", Some("gebeichtet"), false),
+        ("besser", "bessern", Some("gebessert"), false),
+        ("geig", "geigen", Some("gegeigt"), false),
+        ("rechn", "rechnen", Some("gerechnet"), false),
+        ("zeichn", "zeichnen", Some("gezeichnet"), false),
+        ("gähn", "gähnen", Some("gegähnt"), false),
+        ("bestell", "bestellen", None, true),
+    ] {
+        let wiki_text = format!("{{{{Deutsch Verb regelmäßig|2={}}}}}", stem);
+        let output = parse_wiktionary_de::parse_flexion(
+            "Flexion:",
+            &wiki_text,
+            &parse_wiktionary_de::create_configuration()
+                .parse(&wiki_text)
+                .nodes,
+        );
+        assert_eq!(
+            output
+                .warnings
+                .iter()
+                .map(|warning| warning.message)
+                .collect::<Vec<_>>(),
+            if *ambiguous {
+                vec![parse_wiktionary_de::WarningMessage::FormAmbiguous]
+            } else {
+                vec![]
+            }
+        );
+        let find = |non_finite| {
+            output.paradigms[0]
+                .forms
+                .iter()
+                .find(|form| form.features.non_finite == Some(non_finite))
+                .map(|form| &form.form as &str)
+        };
+        assert_eq!(
+            find(parse_wiktionary_de::NonFinite::Infinitive),
+            Some(*infinitive)
+        );
+        assert_eq!(
+            find(parse_wiktionary_de::NonFinite::PastParticiple),
+            *past_participle
+        );
+        assert_eq!(
+            output.paradigms[0]
+                .forms
+                .iter()
+                .any(|form| form.features.tense == Some(parse_wiktionary_de::Tense::Perfect)),
+            past_participle.is_some()
+        );
+    }
+    let wiki_text = "{{Deutsch Adjektiv regelmäßig|schön|schöner|schönst}}";
+    let output = parse_wiktionary_de::parse_flexion(
+        "Flexion:schön",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    assert!(output.paradigms[0]
+        .forms
+        .iter()
+        .any(|form| form.form == "schönerem"
+            && form.features.declension == Some(parse_wiktionary_de::Declension::Strong)
+            && form.features.case == Some(parse_wiktionary_de::Case::Dative)));
+    for (lemma, parameters, genitive) in &[
+        ("Lehrer", "stark|Plural=Lehrer", Some("Lehrers")),
+        ("Tag", "stark|Plural=Tage", None),
+        ("Name", "schwach|Genitiv=Namens", Some("Namens")),
+        ("Bär", "schwach", Some("Bären")),
+    ] {
+        let wiki_text = format!("{{{{Deutsch Substantiv m {}}}}}", parameters);
+        let output = parse_wiktionary_de::parse_flexion(
+            &format!("Flexion:{}", lemma),
+            &wiki_text,
+            &parse_wiktionary_de::create_configuration()
+                .parse(&wiki_text)
+                .nodes,
+        );
+        assert_eq!(output.warnings.is_empty(), genitive.is_some());
+        assert_eq!(
+            output.paradigms[0]
+                .forms
+                .iter()
+                .find(|form| form.features.case == Some(parse_wiktionary_de::Case::Genitive)
+                    && form.features.number == Some(parse_wiktionary_de::Number::Singular))
+                .map(|form| &form.form as &str),
+            *genitive
+        );
+    }
+}
+
+#[test]
+fn senses() {
+    let wiki_text = concat!(
+        "== Bank ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Synonyme}}\n",
+        ":[1, 2] [[Sitzbank]]\n",
+        ":[1–3] [[Bankhaus]]\n",
+        ":[[Geldinstitut]]\n",
+        ":[1 2] [[Geldhaus]]\n",
+        "{{Beispiele}}\n",
+        ":[2a] Die Bank hat heute geschlossen."
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bank",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_de::WarningMessage::ValueUnrecognized
+    );
+    let pos_entry = &output.language_entries[0].pos_entries[0];
+    let numbers = |senses: &[parse_wiktionary_de::Sense]| {
+        senses.iter().map(|sense| sense.number).collect::<Vec<_>>()
+    };
+    assert_eq!(numbers(&pos_entry.synonyms[0].senses), [1, 2]);
+    assert_eq!(numbers(&pos_entry.synonyms[1].senses), [1, 2, 3]);
+    assert!(pos_entry.synonyms[2].senses.is_empty());
+    assert!(pos_entry.synonyms[3].senses.is_empty());
+    match pos_entry.synonyms[1].content.first() {
+        Some(parse_wiktionary_de::Flowing::Link { target, .. }) => {
+            assert_eq!(target, "Bankhaus")
+        }
+        _ => panic!("the sense reference was not removed from the content"),
+    }
+    let example = &pos_entry.examples[0];
+    assert_eq!(example.senses.len(), 1);
+    assert_eq!(example.senses[0].number, 2);
+    assert_eq!(example.senses[0].letter, Some('a'));
+}
+
+#[test]
+fn noun_inflection() {
+    let wiki_text = concat!(
+        "== Joghurt ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}}, {{m}}, {{n}} ===\n",
+        "{{Deutsch Substantiv Übersicht\n",
+        "|Genus 2=n\n",
+        "|Genus 1=m\n",
+        "|Nominativ Singular=Joghurt\n",
+        "|Nominativ Plural 1=Joghurts\n",
+        "|Nominativ Plural 2=Joghurt\n",
+        "|Genitiv Singular=Joghurts\n",
+        "|Genitiv Singular*=Joghurt\n",
+        "|Genitiv Plural 1=Joghurts\n",
+        "|Genitiv Plural 2=Joghurt\n",
+        "|Dativ Singular=Joghurt\n",
+        "|Dativ Plural 1=Joghurts\n",
+        "|Dativ Plural 2=Joghurten\n",
+        "|Akkusativ Singular=Joghurt\n",
+        "|Akkusativ Plural 1=Joghurts\n",
+        "|Akkusativ Plural 2=Joghurt\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Joghurt",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let inflection = output.language_entries[0].pos_entries[0]
+        .noun_inflection
+        .as_ref()
+        .unwrap();
+    assert_eq!(
+        inflection.genders,
+        [
+            parse_wiktionary_de::Gender::Masculine,
+            parse_wiktionary_de::Gender::Neuter
+        ]
+    );
+    assert_eq!(inflection.nominative_singular.len(), 1);
+    assert_eq!(inflection.nominative_singular[0].form, "Joghurt");
+    assert_eq!(inflection.nominative_singular[0].variant, None);
+    let plural = &inflection.dative_plural;
+    assert_eq!(plural.len(), 2);
+    assert_eq!(plural[1].form, "Joghurten");
+    assert_eq!(plural[1].variant, Some(2));
+    let genitive = &inflection.genitive_singular;
+    assert_eq!(genitive.len(), 2);
+    assert!(!genitive[0].alternative && genitive[1].alternative);
+    assert_eq!(genitive[1].form, "Joghurt");
+    assert_eq!(inflection.accusative_plural.len(), 2);
+}
+
+#[test]
+fn verb_overview() {
+    let wiki_text = concat!(
+        "== backen ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Verb|Deutsch}} ===\n",
+        "{{Deutsch Verb Übersicht\n",
+        "|Präsens_ich=backe\n",
+        "|Präsens_du=bäckst\n",
+        "|Präsens_du*=backst\n",
+        "|Präteritum_ich=backte\n",
+        "|Präteritum_ich 2=buk\n",
+        "|Partizip II=gebacken\n",
+        "|Konjunktiv II_ich=—\n",
+        "|Hilfsverb=haben\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "backen",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let overview = output.language_entries[0].pos_entries[0]
+        .verb_overview
+        .as_ref()
+        .unwrap();
+    assert_eq!(
+        overview.auxiliaries,
+        [parse_wiktionary_de::Auxiliary::Have]
+    );
+    assert_eq!(overview.present_second_singular.len(), 2);
+    assert!(overview.present_second_singular[1].alternative);
+    assert_eq!(overview.present_second_singular[1].form, "backst");
+    assert_eq!(overview.preterite_first_singular[1].form, "buk");
+    assert_eq!(overview.preterite_first_singular[1].variant, Some(2));
+    assert_eq!(overview.past_participle[0].form, "gebacken");
+    assert!(overview.subjunctive_ii_first_singular.is_empty());
+    let wiki_text = concat!(
+        "== backen ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Verb|Deutsch}} ===\n",
+        "{{Deutsch Verb Übersicht\n",
+        "|Hilfsverb=[[haben]]\n",
+        "|Präsens_ich=[[backe]]\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "backen",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 2);
+    assert!(output
+        .warnings
+        .iter()
+        .all(|warning| warning.message == parse_wiktionary_de::WarningMessage::ValueUnrecognized));
+}
+
+#[test]
+fn form_index() {
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}}, {{n}} ===\n",
+        "{{Deutsch Substantiv Übersicht\n",
+        "|Genus=n\n",
+        "|Nominativ Singular=Haus\n",
+        "|Nominativ Plural=Häuser\n",
+        "|Genitiv Singular=Hauses\n",
+        "|Genitiv Plural=Häuser\n",
+        "|Dativ Singular=Haus\n",
+        "|Dativ Singular*=Hause\n",
+        "|Dativ Plural=Häusern\n",
+        "|Akkusativ Singular=Haus\n",
+        "|Akkusativ Plural=Häuser\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let mut index = parse_wiktionary_de::FormIndex::new();
+    index.add_output("Haus", &output);
+    let entries = index.get("Häuser");
+    assert_eq!(entries.len(), 3);
+    assert!(entries.iter().all(|entry| entry.lemma == "Haus"
+        && entry.pos == parse_wiktionary_de::Pos::Noun
+        && entry.features.number == Some(parse_wiktionary_de::Number::Plural)));
+    assert_eq!(index.get("Hause").len(), 1);
+    assert!(index
+        .get("Häusern")
+        .iter()
+        .any(|entry| entry.features.case == Some(parse_wiktionary_de::Case::Dative)));
+    assert!(index.get("Hauser").is_empty());
+}
+
+#[test]
+fn form_of() {
+    let wiki_text = concat!(
+        "== Häuser ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Deklinierte Form|Deutsch}} ===\n",
+        "{{Grammatische Merkmale}}\n",
+        "*Nominativ Plural des Substantivs '''[[Haus]]'''\n",
+        "*Genitiv Plural des Substantivs '''[[Haus]]'''\n",
+        "*Akkusativ Plural des Substantivs '''[[Haus]]'''\n",
+        "{{Grundformverweis Dekl|Haus}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Häuser",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let form_of = output.language_entries[0].pos_entries[0]
+        .form_of
+        .as_ref()
+        .unwrap();
+    assert_eq!(form_of.features.len(), 3);
+    assert_eq!(form_of.lemma.as_ref().unwrap(), "Haus");
+    assert_eq!(form_of.pos, Some(parse_wiktionary_de::Pos::Noun));
+    assert_eq!(form_of.target.as_ref().unwrap(), "Haus");
+    let mut index = parse_wiktionary_de::FormIndex::new();
+    index.add_output("Häuser", &output);
+    let entries = index.get("Häuser");
+    assert_eq!(entries.len(), 3);
+    assert!(entries.iter().all(|entry| entry.lemma == "Haus"));
+}
+
+#[test]
+fn languages() {
+    assert_eq!(
+        parse_wiktionary_de::Language::from_name("Latein"),
+        Some(parse_wiktionary_de::Language::La)
+    );
+    for &language in parse_wiktionary_de::Language::ALL {
+        assert_eq!(
+            parse_wiktionary_de::Language::from_name(language.name()),
+            Some(language)
+        );
+        assert_eq!(
+            parse_wiktionary_de::Language::from_code(language.code()),
+            Some(language)
+        );
+        if let Some(code) = language.iso639_3() {
+            assert_eq!(
+                parse_wiktionary_de::Language::from_code(code),
+                Some(language),
+                "{}",
+                code
+            );
+        }
+    }
+    assert_eq!(parse_wiktionary_de::Language::En.iso639_3(), Some("eng"));
+    for (code, language) in &[
+        ("deu", parse_wiktionary_de::Language::De),
+        ("fry", parse_wiktionary_de::Language::Fy),
+        ("nld", parse_wiktionary_de::Language::Nl),
+        ("zho", parse_wiktionary_de::Language::Zh),
+        ("zh-tw", parse_wiktionary_de::Language::ZhTw),
+    ] {
+        assert_eq!(
+            parse_wiktionary_de::Language::from_code(code),
+            Some(*language)
+        );
+    }
+    for name in &[
+        "Altsächsisch",
+        "Elsässisch",
+        "Friaulisch",
+        "Hunsrückisch",
+        "International",
+        "Ostfriesisch",
+        "Ripuarisch",
+        "Walser",
+        "Westfriesisch",
+        "Wienerisch",
+        "Zeeländisch",
+        "Zimbrisch",
+    ] {
+        assert!(parse_wiktionary_de::Language::from_name(name).is_some());
+    }
+    assert_eq!(parse_wiktionary_de::Language::Als.iso639_3(), None);
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Bairisch}}) ==\n",
+        "=== {{Wortart|Substantiv|Bairisch}} ===\n",
+        "{{Bairisch Substantiv Übersicht n\n",
+        "|Nominativ Singular=Haus\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    assert_eq!(
+        output.language_entries[0].language,
+        parse_wiktionary_de::Language::Bar
+    );
+    assert!(output.language_entries[0].pos_entries[0].overview.is_some());
+    let wiki_text = concat!(
+        "== km ({{Sprache|International}}) ==\n",
+        "=== {{Wortart|Symbol|International}} ===\n",
+        "{{Bedeutungen}}\n",
+        ":[1] Kilometer"
+    );
+    let output = parse_wiktionary_de::parse(
+        "km",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    assert_eq!(
+        output.language_entries[0].language,
+        parse_wiktionary_de::Language::Mul
+    );
+}
+
+#[test]
+fn pos() {
+    for &pos in parse_wiktionary_de::Pos::ALL {
+        assert_eq!(parse_wiktionary_de::Pos::from_name(pos.name()), Some(pos));
+    }
+    let wiki_text = concat!(
+        "== er ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Personalpronomen|Deutsch}} ===\n",
+        "{{Bedeutungen}}\n",
+        ":[1] männliche Person"
+    );
+    let output = parse_wiktionary_de::parse(
+        "er",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    assert_eq!(
+        output.language_entries[0].pos_entries[0].pos,
+        parse_wiktionary_de::Pos::PersonalPronoun
+    );
+    let wiki_text = concat!(
+        "== drei ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Kardinalzahl|Deutsch}} ===\n",
+        "{{Kardinalzahl 2-12|Genitiv=dreier|Dativ=dreien}}\n",
+        "{{Bedeutungen}}\n",
+        ":[1] die natürliche Zahl zwischen zwei und vier"
+    );
+    let output = parse_wiktionary_de::parse(
+        "drei",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let pos_entry = &output.language_entries[0].pos_entries[0];
+    assert_eq!(pos_entry.pos, parse_wiktionary_de::Pos::CardinalNumber);
+    assert!(pos_entry.overview.is_some());
+    assert_eq!(pos_entry.definitions.len(), 1);
+}
+
+#[test]
+fn parts_of_speech() {
+    let wiki_text = concat!(
+        "== schnell ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Adjektiv|Deutsch}}, {{Wortart|Adverb|Deutsch}} ===\n",
+        "== schnell ({{Sprache|Niederländisch}}) ==\n",
+        "=== {{Wortart|Substantiv|Niederländisch}}, {{mn.}} ==="
+    );
+    let output = parse_wiktionary_de::parse(
+        "schnell",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    assert_eq!(
+        output.language_entries[0].pos_entries[0].parts_of_speech,
+        [
+            parse_wiktionary_de::Pos::Adjective,
+            parse_wiktionary_de::Pos::Adverb
+        ]
+    );
+    assert_eq!(
+        output.language_entries[1].pos_entries[0].genders,
+        [
+            parse_wiktionary_de::Gender::Masculine,
+            parse_wiktionary_de::Gender::Neuter
+        ]
+    );
+    let mut index = parse_wiktionary_de::FormIndex::new();
+    index.add_output("schnell", &output);
+    assert_eq!(index.get("schnell").len(), 3);
+}
+
+#[test]
+fn genders() {
+    let wiki_text = concat!(
+        "== Bank ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}}, {{m}} ===\n",
+        "{{Deutsch Substantiv Übersicht\n",
+        "|Genus=f\n",
+        "|Nominativ Singular=Bank\n",
+        "}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bank",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_de::WarningMessage::ValueConflicting
+    );
+    assert_eq!(
+        output.language_entries[0].pos_entries[0].genders,
+        [parse_wiktionary_de::Gender::Masculine]
+    );
+}
+
+#[test]
+fn labels() {
+    let wiki_text = concat!(
+        "== Bude ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Bedeutungen}}\n",
+        ":[1] {{K|ugs.|übertr.|t2=_|ft=meist abwertend}} Wohnung\n",
+        ":[2] {{österr.}} Verkaufsstand\n",
+        ":[3] {{ugs.}}, {{va.}} Gaststätte"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bude",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let definitions = &output.language_entries[0].pos_entries[0].definitions;
+    assert_eq!(
+        definitions[0].labels,
+        [
+            parse_wiktionary_de::LabelReference::Known(parse_wiktionary_de::Label::Colloquial),
+            parse_wiktionary_de::LabelReference::Known(parse_wiktionary_de::Label::Figurative),
+            parse_wiktionary_de::LabelReference::Unknown("meist abwertend".into())
+        ]
+    );
+    assert_eq!(
+        definitions[0]
+            .labels
+            .iter()
+            .map(parse_wiktionary_de::LabelReference::name)
+            .collect::<Vec<_>>(),
+        ["umgangssprachlich", "übertragen", "meist abwertend"]
+    );
+    assert_eq!(definitions[0].gloss.len(), 1);
+    assert_eq!(
+        definitions[1].labels,
+        [parse_wiktionary_de::LabelReference::Known(
+            parse_wiktionary_de::Label::Austrian
+        )]
+    );
+    assert_eq!(
+        definitions[2].labels,
+        [
+            parse_wiktionary_de::LabelReference::Known(parse_wiktionary_de::Label::Colloquial),
+            parse_wiktionary_de::LabelReference::Known(parse_wiktionary_de::Label::Obsolete)
+        ]
+    );
+    match &definitions[2].gloss[..] {
+        [parse_wiktionary_de::Flowing::Text { value }] => assert_eq!(value, "Gaststätte"),
+        _ => panic!("the labels were not removed from the gloss"),
+    }
+    assert_eq!(
+        parse_wiktionary_de::Label::Austrian.kind(),
+        parse_wiktionary_de::LabelKind::Region
+    );
+}
+
+#[test]
+fn hyphenation() {
+    let wiki_text = concat!(
+        "== Bank ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Worttrennung}}\n",
+        ":Bank, {{Pl.1}} Bän·ke, {{Pl.2}} Ban·ken\n",
+        ":Obst, {{kPl.}}\n",
+        ":{{Pl.}} Leu·te\n",
+        ":{{kSg.}}, {{Pl.}} Leu·te\n",
+        ":Haus, {{Gen.}} Hau·ses\n",
+        ":Haus, {{kPl.}}, ''selten:'' Häu·ser\n",
+        ":Obst, kein Plural"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bank",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let hyphenation = &output.language_entries[0].pos_entries[0].hyphenation;
+    assert_eq!(hyphenation.len(), 7);
+    assert_eq!(hyphenation[0][0].form, None);
+    assert_eq!(hyphenation[0][0].alternatives, [["Bank"]]);
+    assert_eq!(
+        hyphenation[0][1].form,
+        Some(parse_wiktionary_de::WordForm::Plural1)
+    );
+    assert_eq!(hyphenation[0][1].alternatives, [["Bän", "ke"]]);
+    assert_eq!(hyphenation[0][2].alternatives, [["Ban", "ken"]]);
+    assert_eq!(
+        hyphenation[1][1].form,
+        Some(parse_wiktionary_de::WordForm::Plural)
+    );
+    assert!(hyphenation[1][1].missing);
+    assert_eq!(hyphenation[2].len(), 1);
+    assert_eq!(
+        hyphenation[2][0].form,
+        Some(parse_wiktionary_de::WordForm::Plural)
+    );
+    assert_eq!(hyphenation[2][0].alternatives, [["Leu", "te"]]);
+    assert_eq!(
+        hyphenation[3][0].form,
+        Some(parse_wiktionary_de::WordForm::Singular)
+    );
+    assert!(hyphenation[3][0].missing);
+    assert_eq!(hyphenation[3][1].alternatives, [["Leu", "te"]]);
+    assert_eq!(
+        hyphenation[4][1].form,
+        Some(parse_wiktionary_de::WordForm::Genitive)
+    );
+    assert_eq!(hyphenation[4][1].alternatives, [["Hau", "ses"]]);
+    assert!(hyphenation[5][1].missing);
+    assert_eq!(hyphenation[5][1].alternatives, [["Häu", "ser"]]);
+    assert_eq!(hyphenation[6].len(), 2);
+    assert_eq!(hyphenation[6][0].form, None);
+    assert!(!hyphenation[6][0].missing);
+    assert_eq!(hyphenation[6][0].alternatives, [["Obst"]]);
+    assert_eq!(
+        hyphenation[6][1].form,
+        Some(parse_wiktionary_de::WordForm::Plural)
+    );
+    assert!(hyphenation[6][1].missing);
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Worttrennung}}\n",
+        ":[[Haus]], {{Pl.}} Häu·ser"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_de::WarningMessage::ValueUnrecognized
+    );
+    assert!(output.language_entries[0].pos_entries[0]
+        .hyphenation
+        .is_empty());
+}
+
+#[test]
+fn ipa() {
+    let wiki_text = concat!(
+        "== Bank ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Aussprache}}\n",
+        ":{{IPA}} {{Lautschrift|baŋk}}, {{Pl.1}} {{Lautschrift|ˈbɛŋkə}}, ",
+        "{{Pl.2}} {{Lautschrift|ˈbaŋkn̩}}, ''österr.:'' {{Lautschrift|ˈbaŋkɛn}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bank",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let ipa = &output.language_entries[0].pos_entries[0].ipa;
+    assert_eq!(ipa.len(), 4);
+    assert_eq!(ipa[0].form, None);
+    assert_eq!(ipa[0].ipa, "baŋk");
+    assert_eq!(ipa[1].form, Some(parse_wiktionary_de::WordForm::Plural1));
+    assert!(ipa[2].labels.is_empty());
+    assert_eq!(ipa[3].form, Some(parse_wiktionary_de::WordForm::Plural2));
+    assert_eq!(
+        ipa[3].labels,
+        [parse_wiktionary_de::LabelReference::Known(
+            parse_wiktionary_de::Label::Austrian
+        )]
+    );
+}
+
+#[test]
+fn ipa_tokens() {
+    assert_eq!(
+        parse_wiktionary_de::tokenize_ipa("ˈt͡saɪ̯t.ʃaːn"),
+        [
+            parse_wiktionary_de::IpaToken::PrimaryStress,
+            parse_wiktionary_de::IpaToken::Segment("t͡s"),
+            parse_wiktionary_de::IpaToken::Segment("a"),
+            parse_wiktionary_de::IpaToken::Segment("ɪ̯"),
+            parse_wiktionary_de::IpaToken::Segment("t"),
+            parse_wiktionary_de::IpaToken::SyllableBoundary,
+            parse_wiktionary_de::IpaToken::Segment("ʃ"),
+            parse_wiktionary_de::IpaToken::Segment("a"),
+            parse_wiktionary_de::IpaToken::Long,
+            parse_wiktionary_de::IpaToken::Segment("n")
+        ]
+    );
+    assert_eq!(
+        parse_wiktionary_de::tokenize_ipa("ʃãˈsõː"),
+        [
+            parse_wiktionary_de::IpaToken::Segment("ʃ"),
+            parse_wiktionary_de::IpaToken::Segment("ã"),
+            parse_wiktionary_de::IpaToken::PrimaryStress,
+            parse_wiktionary_de::IpaToken::Segment("s"),
+            parse_wiktionary_de::IpaToken::Segment("õ"),
+            parse_wiktionary_de::IpaToken::Long
+        ]
+    );
+    assert_eq!(
+        parse_wiktionary_de::tokenize_ipa("ja | naɪ̯n ‖"),
+        [
+            parse_wiktionary_de::IpaToken::Segment("j"),
+            parse_wiktionary_de::IpaToken::Segment("a"),
+            parse_wiktionary_de::IpaToken::WordBoundary,
+            parse_wiktionary_de::IpaToken::MinorBreak,
+            parse_wiktionary_de::IpaToken::WordBoundary,
+            parse_wiktionary_de::IpaToken::Segment("n"),
+            parse_wiktionary_de::IpaToken::Segment("a"),
+            parse_wiktionary_de::IpaToken::Segment("ɪ̯"),
+            parse_wiktionary_de::IpaToken::Segment("n"),
+            parse_wiktionary_de::IpaToken::WordBoundary,
+            parse_wiktionary_de::IpaToken::MajorBreak
+        ]
+    );
+    let wiki_text = concat!(
+        "== Bahn ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Aussprache}}\n",
+        ":{{IPA}} {{Lautschrift|'ba:n}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bahn",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_de::WarningMessage::IpaInvalid
+    );
+    assert_eq!(
+        output.language_entries[0].pos_entries[0].ipa[0].ipa,
+        "'ba:n"
+    );
+}
+
+#[test]
+fn audio() {
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Aussprache}}\n",
+        ":{{Hörbeispiele}} {{Audio|De-Haus.ogg}}, {{Pl.}} {{Audio|De-at-Häuser.ogg}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let audio = &output.language_entries[0].pos_entries[0].audio;
+    assert_eq!(audio.len(), 2);
+    assert_eq!(audio[0].form, None);
+    assert_eq!(audio[0].region, None);
+    assert_eq!(audio[1].form, Some(parse_wiktionary_de::WordForm::Plural));
+    assert_eq!(audio[1].region, Some(parse_wiktionary_de::Label::Austrian));
+    assert_eq!(audio[0].commons_path(), "7/7e/De-Haus.ogg");
+    let sample = parse_wiktionary_de::AudioSample {
+        file_name: "example.jpg".into(),
+        form: None,
+        label: None,
+        language: None,
+        region: None,
+    };
+    assert_eq!(sample.commons_path(), "a/a9/Example.jpg");
+}
+
+#[test]
+fn rhymes() {
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Worttrennung}}\n",
+        ":Haus, {{Pl.}} Häu·ser\n",
+        "{{Aussprache}}\n",
+        ":{{Reime}} {{Reim|aʊ̯s|Deutsch}}"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let wiki_text = concat!(
+        "== 1 Silbe ==\n",
+        "[[Haus]], [[Maus]]\n",
+        "== Zweisilbige Reime ==\n",
+        "* [[daraus]]\n",
+        "* [[Reim:Deutsch:-aʊ̯]]\n",
+        ":[[Graus#Deutsch|Graus]]"
+    );
+    let rhyme_page = parse_wiktionary_de::parse_rhyme_page(
+        "Reim:Deutsch:-aʊ̯s",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(rhyme_page.warnings.is_empty());
+    assert_eq!(rhyme_page.language, Some(parse_wiktionary_de::Language::De));
+    assert_eq!(rhyme_page.rhyme, "aʊ̯s");
+    assert_eq!(rhyme_page.words.len(), 4);
+    assert_eq!(rhyme_page.words[2].syllables, Some(2));
+    assert_eq!(rhyme_page.words[3].word, "Graus");
+    let mut index = parse_wiktionary_de::RhymeIndex::new();
+    index.add_rhyme_page(&rhyme_page);
+    index.add_output("Haus", &output);
+    let entries = index.get("-aʊ̯s");
+    assert_eq!(entries.len(), 4);
+    let haus = entries.iter().find(|entry| entry.lemma == "Haus").unwrap();
+    assert!(haus.in_entry && haus.in_rhyme_page);
+    assert_eq!(haus.pos, Some(parse_wiktionary_de::Pos::Noun));
+    assert_eq!(haus.syllables, Some(1));
+    assert!(entries
+        .iter()
+        .filter(|entry| entry.lemma != "Haus")
+        .all(|entry| !entry.in_entry && entry.pos.is_none()));
+}
+
+#[test]
+fn etymology() {
+    let wiki_text = concat!(
+        "== Haustür ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Herkunft}}\n",
+        ":[1] [[Determinativkompositum]] aus ''[[Haus]]'' und ''[[Tür]]''\n",
+        ":[2] Erbwort von {{mhd.}} ''hūs'' „Haus“\n",
+        ":[3] Entlehnung aus dem {{fr.}} ''nation,'' „Volk“\n",
+        ":[4] unklar\n",
+        ":[5] Determinativkompositum aus den Substantiven [[Ausstellung]] und [[wohnen]] sowie dem [[Fugenelement]] [[-s]]\n",
+        ":[6] Ableitung von ''Haus'', das keine Ableitung von ''Hut'' ist\n",
+        ":[7] von {{lat.}} ''natio''\n",
+        ":[8] über {{fr.}} ''nation'' aus {{lat.}} ''natio''\n",
+        ":[9] Entlehnung aus dem {{fr.}} ''nation,'' das auf {{lat.}} ''natio'' zurückgeht\n",
+        ":[10] von {{mhd.}} ''hūs'', {{ahd.}} ''hūs'' „Haus“\n",
+        ":[11] im 16. Jahrhundert von {{lat.}} ''natio'' „Geburt“ entlehnt"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haustür",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let etymology = &output.language_entries[0].pos_entries[0].etymology;
+    assert_eq!(etymology.len(), 11);
+    let steps = &etymology[0].steps;
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].form, "Haus");
+    assert_eq!(steps[1].form, "Tür");
+    assert!(steps.iter().all(|step| step.language.is_none()
+        && step.relation == parse_wiktionary_de::EtymologyRelation::Compound));
+    let steps = &etymology[1].steps;
+    assert_eq!(steps.len(), 1);
+    assert_eq!(
+        steps[0].language,
+        Some(parse_wiktionary_de::LanguageReference::Known(
+            parse_wiktionary_de::Language::Gmh
+        ))
+    );
+    assert_eq!(steps[0].gloss.as_ref().unwrap(), "Haus");
+    assert_eq!(
+        steps[0].relation,
+        parse_wiktionary_de::EtymologyRelation::Inherited
+    );
+    let steps = &etymology[2].steps;
+    assert_eq!(steps.len(), 1);
+    assert_eq!(steps[0].form, "nation");
+    assert_eq!(steps[0].gloss.as_ref().unwrap(), "Volk");
+    assert_eq!(
+        steps[0].relation,
+        parse_wiktionary_de::EtymologyRelation::Borrowed
+    );
+    assert!(etymology[3].steps.is_empty());
+    assert!(!etymology[3].content.is_empty());
+    let steps = &etymology[4].steps;
+    assert_eq!(
+        steps.iter().map(|step| &step.form).collect::<Vec<_>>(),
+        ["Ausstellung", "wohnen"]
+    );
+    assert!(steps
+        .iter()
+        .all(|step| step.relation == parse_wiktionary_de::EtymologyRelation::Compound));
+    let steps = &etymology[5].steps;
+    assert_eq!(steps.len(), 1);
+    assert_eq!(steps[0].form, "Haus");
+    // Without a keyword for each form, the relations can't be classified.
+    for etymology in &etymology[6..9] {
+        assert!(etymology.steps.is_empty());
+    }
+    let steps = &etymology[9].steps;
+    assert_eq!(
+        steps
+            .iter()
+            .map(|step| step.language.as_ref().unwrap().code())
+            .collect::<Vec<_>>(),
+        ["gmh", "goh"]
+    );
+    assert_eq!(steps[1].gloss.as_ref().unwrap(), "Haus");
+    assert!(steps
+        .iter()
+        .all(|step| step.relation == parse_wiktionary_de::EtymologyRelation::Inherited));
+    let steps = &etymology[10].steps;
+    assert_eq!(steps.len(), 1);
+    assert_eq!(steps[0].form, "natio");
+    assert_eq!(steps[0].gloss.as_ref().unwrap(), "Geburt");
+    assert_eq!(
+        steps[0].relation,
+        parse_wiktionary_de::EtymologyRelation::Borrowed
+    );
+}
+
+#[test]
+fn compound() {
+    let wiki_text = concat!(
+        "== Ausstellungswohnung ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Herkunft}}\n",
+        ":[[Determinativkompositum]] aus den Substantiven [[Ausstellung]] und [[Wohnung]] sowie dem [[Fugenelement]] [[-s]]\n",
+        ":Ableitung vom Verb ''[[lesen]]'' mit dem Derivatem [[-ung]]. Belegt seit dem 15. Jahrhundert.\n",
+        ":Determinativkompositum aus [[Ausstellung]]\n",
+        ":von {{mhd.}} ''wonunge''\n",
+        ":Determinativkompositum aus dem Verb [[wohnen]] und [[Haus]]\n",
+        ":Ableitung zum Verb ''[[hausen]]'' mit dem Derivatem ([[Ableitungsmorphem]]) ''[[-ung]]''"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Ausstellungswohnung",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let etymology = &output.language_entries[0].pos_entries[0].etymology;
+    let compound = etymology[0].compound().unwrap();
+    assert_eq!(
+        compound.kind,
+        parse_wiktionary_de::CompoundKind::Determinative
+    );
+    assert_eq!(compound.constituents.len(), 2);
+    assert_eq!(compound.constituents[0].lemma, "Ausstellung");
+    assert_eq!(compound.constituents[1].lemma, "Wohnung");
+    assert!(compound
+        .constituents
+        .iter()
+        .all(|constituent| constituent.pos == Some(parse_wiktionary_de::Pos::Noun)));
+    assert_eq!(compound.linking_element.unwrap(), "-s");
+    let compound = etymology[1].compound().unwrap();
+    assert_eq!(compound.kind, parse_wiktionary_de::CompoundKind::Derivation);
+    assert_eq!(compound.constituents.len(), 2);
+    assert_eq!(
+        compound.constituents[0].pos,
+        Some(parse_wiktionary_de::Pos::Verb)
+    );
+    assert_eq!(compound.constituents[1].lemma, "-ung");
+    assert_eq!(
+        compound.constituents[1].pos,
+        Some(parse_wiktionary_de::Pos::Suffix)
+    );
+    assert!(compound.linking_element.is_none());
+    assert!(etymology[2].compound().is_none());
+    assert!(etymology[3].compound().is_none());
+    let compound = etymology[4].compound().unwrap();
+    assert_eq!(
+        compound.constituents[0].pos,
+        Some(parse_wiktionary_de::Pos::Verb)
+    );
+    assert_eq!(compound.constituents[1].lemma, "Haus");
+    assert_eq!(compound.constituents[1].pos, None);
+    let compound = etymology[5].compound().unwrap();
+    assert_eq!(compound.kind, parse_wiktionary_de::CompoundKind::Derivation);
+    assert_eq!(
+        compound
+            .constituents
+            .iter()
+            .map(|constituent| (&constituent.lemma as &str, constituent.pos))
+            .collect::<Vec<_>>(),
+        [
+            ("hausen", Some(parse_wiktionary_de::Pos::Verb)),
+            ("-ung", Some(parse_wiktionary_de::Pos::Suffix))
+        ]
+    );
+}
+
+#[test]
+fn word_formations() {
+    let wiki_text = concat!(
+        "== Haus ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Wortbildungen}}\n",
+        ":Adjektive: [[häuslich]]; Substantive: [[Haustür]], [[Hausfrau]]\n",
+        ":Konversionen: [[hausen]]\n",
+        ":{{Wortbildung|Verb}} [[behausen]]\n",
+        ":[[Gehäuse]]"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Haus",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let groups = &output.language_entries[0].pos_entries[0].word_formations;
+    assert_eq!(groups.len(), 5);
+    assert_eq!(groups[0].pos, Some(parse_wiktionary_de::Pos::Adjective));
+    assert_eq!(groups[0].lemmas, ["häuslich"]);
+    assert_eq!(groups[1].pos, Some(parse_wiktionary_de::Pos::Noun));
+    assert_eq!(groups[1].lemmas, ["Haustür", "Hausfrau"]);
+    assert_eq!(groups[2].label.as_ref().unwrap(), "Konversionen");
+    assert_eq!(groups[2].pos, None);
+    assert_eq!(groups[3].pos, Some(parse_wiktionary_de::Pos::Verb));
+    assert_eq!(groups[3].lemmas, ["behausen"]);
+    assert!(groups[4].label.is_none() && groups[4].pos.is_none());
+}
+
+#[test]
+fn relations() {
+    let wiki_text = concat!(
+        "== Bank ({{Sprache|Deutsch}}) ==\n",
+        "=== {{Wortart|Substantiv|Deutsch}} ===\n",
+        "{{Synonyme}}\n",
+        ":[1] [[Kreditinstitut]], [[Geldhaus]] ''(umgangssprachlich)''; {{ugs.}} [[Sparkasse]] (abwertend)\n",
+        ":[2] ''ugs.:'' [[Sitzgelegenheit]], [[Sitz]]; [3] [[Sandbank]] ''(Binnenschifffahrt)''\n",
+        ":[4] [[Kasse]] (auch [[Geldkasse]]), [[Tresor]] ''(selten auch)''\n",
+        "{{Redewendungen}}\n",
+        ":[1] [[durch die Bank]]"
+    );
+    let output = parse_wiktionary_de::parse(
+        "Bank",
+        wiki_text,
+        &parse_wiktionary_de::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.warnings.is_empty());
+    let synonyms = &output.language_entries[0].pos_entries[0].synonyms;
+    let relations = &synonyms[0].relations;
+    assert_eq!(relations.len(), 3);
+    assert_eq!(relations[0].target, "Kreditinstitut");
+    assert!(relations[0].labels.is_empty());
+    assert_eq!(
+        relations[1].labels,
+        [parse_wiktionary_de::LabelReference::Known(
+            parse_wiktionary_de::Label::Colloquial
+        )]
+    );
+    assert_eq!(
+        relations[2].labels,
+        [
+            parse_wiktionary_de::LabelReference::Known(parse_wiktionary_de::Label::Colloquial),
+            parse_wiktionary_de::LabelReference::Known(parse_wiktionary_de::Label::Derogatory)
+        ]
+    );
+    assert!(relations.iter().all(|relation| relation.senses.len() == 1));
+    let relations = &synonyms[1].relations;
+    assert_eq!(relations.len(), 3);
+    assert!(relations[..2].iter().all(|relation| relation.labels.len() == 1));
+    assert_eq!(
+        relations[2].labels,
+        [parse_wiktionary_de::LabelReference::Unknown(
+            "Binnenschifffahrt".into()
+        )]
+    );
+    assert_eq!(relations[2].target, "Sandbank");
+    assert_eq!(relations[2].senses[0].number, 3);
+    let relations = &synonyms[2].relations;
+    assert_eq!(
+        relations
+            .iter()
+            .map(|relation| &relation.target)
+            .collect::<Vec<_>>(),
+        ["Kasse", "Geldkasse", "Tresor"]
+    );
+    assert!(relations[..2]
+        .iter()
+        .all(|relation| relation.labels.is_empty()));
+    assert_eq!(
+        relations[2].labels,
+        [parse_wiktionary_de::LabelReference::Known(
+            parse_wiktionary_de::Label::Rare
+        )]
+    );
+    assert!(output.language_entries[0].pos_entries[0].idioms[0]
+        .relations
+        .is_empty());
+}